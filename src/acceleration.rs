@@ -3,10 +3,21 @@
 use super::length;
 use super::measurement::*;
 #[cfg(feature = "from_str")]
-use regex::Regex;
+use parse::{parse_number, split_number_unit, unknown_unit, ParseMeasurementError};
 #[cfg(feature = "from_str")]
 use std::str::FromStr;
 
+/// Units supported by [`Acceleration`], as (symbol, long name, number of meters per second per second
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("m/s\u{00B2}", "meters per second per second", 1.0),
+    (
+        "ft/s\u{00B2}",
+        "feet per second per second",
+        1.0 / length::METER_FEET_FACTOR,
+    ),
+];
+
 /// The `Acceleration` struct can be used to deal with Accelerations in a common way.
 /// Common metric and imperial units are supported.
 ///
@@ -17,17 +28,16 @@ use std::str::FromStr;
 /// use measurements::{Acceleration, Length, Speed};
 /// use std::time::Duration;
 ///
-/// fn main() {
-///     // Standing quarter mile in 10.0 dead, at 120.0 mph
-///     let track = Length::from_miles(0.25);
-///     let finish = Speed::from_miles_per_hour(120.0);
-///     let time = Duration::new(10, 0);
-///     let accel: Acceleration = finish / time;
-///     println!("You accelerated over {} at an average of {}", track, accel);
-///}
+/// // Standing quarter mile in 10.0 dead, at 120.0 mph
+/// let track = Length::from_miles(0.25);
+/// let finish = Speed::from_miles_per_hour(120.0);
+/// let time = Duration::new(10, 0);
+/// let accel: Acceleration = finish / time;
+/// println!("You accelerated over {} at an average of {}", track, accel);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Acceleration {
     meters_per_second_per_second: f64,
 }
@@ -69,6 +79,8 @@ impl Acceleration {
 }
 
 impl Measurement for Acceleration {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.meters_per_second_per_second
     }
@@ -80,41 +92,39 @@ impl Measurement for Acceleration {
     fn get_base_units_name(&self) -> &'static str {
         "m/s\u{00B2}"
     }
+
+    fn quantity_name() -> &'static str {
+        "Acceleration"
+    }
 }
 
 #[cfg(feature = "from_str")]
 impl FromStr for Acceleration {
-    type Err = std::num::ParseFloatError;
+    type Err = ParseMeasurementError;
 
     /// Create a new Acceleration from a string
     /// Plain numbers in string are considered to be meters per second
     fn from_str(val: &str) -> Result<Self, Self::Err> {
         if val.is_empty() {
-            return Ok(Acceleration::from_metres_per_second_per_second(0.0));
+            return Ok(Acceleration::from_meters_per_second_per_second(0.0));
         }
 
-        let re = Regex::new(r"(?i)\s*([0-9.]*)\s?([ftmps -1]{1,6})\s*$").unwrap();
-        if let Some(caps) = re.captures(val) {
-            let float_val = caps.get(1).unwrap().as_str();
-            return Ok(
-                match caps.get(2).unwrap().as_str().to_lowercase().as_str() {
-                    "m/s" | "m s-1" => {
-                        Acceleration::from_meters_per_second_per_second(float_val.parse::<f64>()?)
-                    }
-                    "ft/s" | "fps" | "ft s-1" => {
-                        Acceleration::from_feet_per_second_per_second(float_val.parse::<f64>()?)
-                    }
-                    _ => Acceleration::from_meters_per_second_per_second(val.parse::<f64>()?),
-                },
-            );
-        }
-
-        Ok(Acceleration::from_meters_per_second_per_second(
-            val.parse::<f64>()?,
-        ))
+        let (number, unit) = split_number_unit(val);
+        Ok(match unit.to_lowercase().as_str() {
+            "" => Acceleration::from_meters_per_second_per_second(parse_number(number)?),
+            "m/s" | "m s-1" => {
+                Acceleration::from_meters_per_second_per_second(parse_number(number)?)
+            }
+            "ft/s" | "fps" | "ft s-1" => {
+                Acceleration::from_feet_per_second_per_second(parse_number(number)?)
+            }
+            _ => return Err(unknown_unit(&unit)),
+        })
     }
 }
 
+implement_debug! { Acceleration, meters_per_second_per_second }
+
 implement_measurement! { Acceleration }
 
 #[cfg(test)]
@@ -0,0 +1,229 @@
+//! Types and constants for handling amounts of substance.
+
+use super::measurement::*;
+use mass::Mass;
+use molar_mass::MolarMass;
+
+/// Units supported by [`AmountOfSubstance`], as (symbol, long name, number of
+/// moles in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("\u{00B5}mol", "micromoles", 1e-6),
+    ("mmol", "millimoles", 1e-3),
+    ("mol", "moles", 1.0),
+    ("kmol", "kilomoles", 1e3),
+];
+
+/// The `AmountOfSubstance` struct can be used to deal with amounts of a
+/// substance in a common way, as a number of moles.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{AmountOfSubstance, Mass};
+///
+/// let water = AmountOfSubstance::of_water(Mass::from_grams(18.0));
+/// println!("18 g of water is {} mol.", water.as_moles());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct AmountOfSubstance {
+    moles: f64,
+}
+
+impl AmountOfSubstance {
+    /// Create a new AmountOfSubstance from a floating point value in moles
+    pub fn from_moles(moles: f64) -> Self {
+        AmountOfSubstance { moles }
+    }
+
+    /// Create a new AmountOfSubstance from a floating point value in millimoles
+    pub fn from_millimoles(millimoles: f64) -> Self {
+        Self::from_moles(millimoles / 1000.0)
+    }
+
+    /// Create a new AmountOfSubstance from a floating point value in micromoles
+    pub fn from_micromoles(micromoles: f64) -> Self {
+        Self::from_moles(micromoles / 1_000_000.0)
+    }
+
+    /// Create a new AmountOfSubstance of a substance with the given
+    /// MolarMass from its Mass (n = m / M)
+    pub fn of_substance(mass: Mass, molar_mass: MolarMass) -> Self {
+        mass / molar_mass
+    }
+
+    /// Create a new AmountOfSubstance of water (H₂O) from its Mass
+    pub fn of_water(mass: Mass) -> Self {
+        Self::of_substance(mass, MolarMass::water())
+    }
+
+    /// Create a new AmountOfSubstance of carbon dioxide (CO₂) from its Mass
+    pub fn of_carbon_dioxide(mass: Mass) -> Self {
+        Self::of_substance(mass, MolarMass::carbon_dioxide())
+    }
+
+    /// Create a new AmountOfSubstance of oxygen gas (O₂) from its Mass
+    pub fn of_oxygen(mass: Mass) -> Self {
+        Self::of_substance(mass, MolarMass::oxygen())
+    }
+
+    /// Create a new AmountOfSubstance of nitrogen gas (N₂) from its Mass
+    pub fn of_nitrogen(mass: Mass) -> Self {
+        Self::of_substance(mass, MolarMass::nitrogen())
+    }
+
+    /// Convert this AmountOfSubstance into a floating point value in moles
+    pub fn as_moles(&self) -> f64 {
+        self.moles
+    }
+
+    /// Convert this AmountOfSubstance into a floating point value in millimoles
+    pub fn as_millimoles(&self) -> f64 {
+        self.moles * 1000.0
+    }
+
+    /// Convert this AmountOfSubstance into a floating point value in micromoles
+    pub fn as_micromoles(&self) -> f64 {
+        self.moles * 1_000_000.0
+    }
+}
+
+impl Measurement for AmountOfSubstance {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.moles
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_moles(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "mol"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("nmol", 1e-9),
+            ("\u{00B5}mol", 1e-6),
+            ("mmol", 1e-3),
+            ("mol", 1e0),
+            ("kmol", 1e3),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "AmountOfSubstance"
+    }
+}
+
+implement_debug! { AmountOfSubstance, moles }
+
+implement_measurement! { AmountOfSubstance }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn millimoles() {
+        let i1 = AmountOfSubstance::from_moles(2.5);
+        let r1 = i1.as_millimoles();
+        let i2 = AmountOfSubstance::from_micromoles(500.0);
+        let r2 = i2.as_moles();
+        assert_almost_eq(r1, 2500.0);
+        assert_almost_eq(r2, 0.0005);
+    }
+
+    #[test]
+    fn of_water() {
+        let water = AmountOfSubstance::of_water(Mass::from_grams(18.0));
+        assert!((water.as_moles() - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn of_common_gases() {
+        let co2 = AmountOfSubstance::of_carbon_dioxide(Mass::from_grams(44.009));
+        assert_almost_eq(co2.as_moles(), 1.0);
+        let o2 = AmountOfSubstance::of_oxygen(Mass::from_kilograms(0.031998));
+        assert_almost_eq(o2.as_moles(), 1.0);
+        let n2 = AmountOfSubstance::of_nitrogen(Mass::from_grams(56.028));
+        assert_almost_eq(n2.as_moles(), 2.0);
+    }
+
+    #[test]
+    fn mass_relations() {
+        let n = AmountOfSubstance::from_moles(2.0);
+        let m: Mass = n * MolarMass::from_grams_per_mole(10.0);
+        assert_almost_eq(m.as_grams(), 20.0);
+        let mm: MolarMass = m / n;
+        assert_almost_eq(mm.as_grams_per_mole(), 10.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = AmountOfSubstance::from_moles(2.0);
+        let b = AmountOfSubstance::from_moles(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_moles(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = AmountOfSubstance::from_moles(2.0);
+        let b = AmountOfSubstance::from_moles(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_moles(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = AmountOfSubstance::from_moles(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_moles(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = AmountOfSubstance::from_moles(2.0);
+        let b = AmountOfSubstance::from_moles(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_moles(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = AmountOfSubstance::from_moles(2.0);
+        let b = AmountOfSubstance::from_moles(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = AmountOfSubstance::from_moles(2.0);
+        let b = AmountOfSubstance::from_moles(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = AmountOfSubstance::from_moles(2.0);
+        let b = AmountOfSubstance::from_moles(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
@@ -1,12 +1,20 @@
 //! Types and constants for handling angles
 
 use super::measurement::*;
+use length::Length;
 
 #[cfg(feature = "from_str")]
-use regex::Regex;
+use parse::{parse_number, split_number_unit, strip_degree, unknown_unit, ParseMeasurementError};
 #[cfg(feature = "from_str")]
 use std::str::FromStr;
 
+/// Units supported by [`Angle`], as (symbol, long name, number of radians
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("rad", "radians", 1.0),
+    ("\u{00B0}", "degrees", ::PI / 180.0),
+];
+
 /// The 'Angle' struct can be used to deal with angles in a common way.
 ///
 /// # Example
@@ -20,7 +28,8 @@ use std::str::FromStr;
 /// println!("Each slice will be {} degrees", slice.as_degrees());
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Angle {
     radians: f64,
 }
@@ -87,9 +96,64 @@ impl Angle {
     pub fn atan(num: f64) -> Self {
         Angle::from_radians(num.atan())
     }
+
+    /// Create a new Angle from a road grade, as a percentage (e.g. 6.0 for a 6% grade)
+    #[cfg(feature = "std")]
+    pub fn from_grade_percent(percent: f64) -> Self {
+        Angle::from_radians((percent / 100.0).atan())
+    }
+
+    /// Create a new Angle from a road grade, as a percentage (e.g. 6.0 for a 6% grade)
+    #[cfg(not(feature = "std"))]
+    pub fn from_grade_percent(percent: f64) -> Self {
+        Angle::from_radians(libm::atan(percent / 100.0))
+    }
+
+    /// Convert this Angle to a road grade, as a percentage (e.g. 6.0 for a 6% grade)
+    #[cfg(feature = "std")]
+    pub fn as_grade_percent(&self) -> f64 {
+        self.radians.tan() * 100.0
+    }
+
+    /// Convert this Angle to a road grade, as a percentage (e.g. 6.0 for a 6% grade)
+    #[cfg(not(feature = "std"))]
+    pub fn as_grade_percent(&self) -> f64 {
+        libm::tan(self.radians) * 100.0
+    }
+
+    /// Create a new Angle from a slope given as rise over run (e.g. 1.0, 12.0 for a 1:12 ramp)
+    #[cfg(feature = "std")]
+    pub fn from_slope_ratio(rise: f64, run: f64) -> Self {
+        Angle::from_radians(rise.atan2(run))
+    }
+
+    /// Create a new Angle from a slope given as rise over run (e.g. 1.0, 12.0 for a 1:12 ramp)
+    #[cfg(not(feature = "std"))]
+    pub fn from_slope_ratio(rise: f64, run: f64) -> Self {
+        Angle::from_radians(libm::atan2(rise, run))
+    }
+
+    /// Convert this Angle to a slope given as (rise, run), normalised to a run of 1.0
+    pub fn as_slope_ratio(&self) -> (f64, f64) {
+        (self.as_grade_percent() / 100.0, 1.0)
+    }
+
+    /// Calculate the length of the arc this Angle subtends on a circle of the
+    /// given radius (θ × r)
+    pub fn arc_length(&self, radius: Length) -> Length {
+        Length::from_meters(self.radians * radius.as_meters())
+    }
+
+    /// Create a new Angle from the length of an arc on a circle of the given
+    /// radius (s / r)
+    pub fn from_arc_length(arc: Length, radius: Length) -> Self {
+        Angle::from_radians(arc.as_meters() / radius.as_meters())
+    }
 }
 
 impl Measurement for Angle {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.radians
     }
@@ -101,11 +165,15 @@ impl Measurement for Angle {
     fn get_base_units_name(&self) -> &'static str {
         "rad"
     }
+
+    fn quantity_name() -> &'static str {
+        "Angle"
+    }
 }
 
 #[cfg(feature = "from_str")]
 impl FromStr for Angle {
-    type Err = std::num::ParseFloatError;
+    type Err = ParseMeasurementError;
 
     /// Create a new Angle from a string
     /// Plain numbers in string are considered to be plain degrees
@@ -114,24 +182,87 @@ impl FromStr for Angle {
             return Ok(Angle::from_degrees(0.0));
         }
 
-        let re = Regex::new(r"(?i)\s*([0-9.]*)\s?(deg|\u{00B0}|rad)\s*$").unwrap();
-        if let Some(caps) = re.captures(val) {
-            let float_val = caps.get(1).unwrap().as_str();
-            return Ok(
-                match caps.get(2).unwrap().as_str().to_lowercase().as_str() {
-                    "deg" | "\u{00B0}" => Angle::from_degrees(float_val.parse::<f64>()?),
-                    "rad" => Angle::from_radians(float_val.parse::<f64>()?),
-                    _ => Angle::from_degrees(val.parse::<f64>()?),
-                },
-            );
+        let (number, unit) = split_number_unit(val);
+        if unit.is_empty() || strip_degree(&unit).is_empty() {
+            return Ok(Angle::from_degrees(parse_number(number)?));
         }
-
-        Ok(Angle::from_degrees(val.parse::<f64>()?))
+        Ok(match unit.to_lowercase().as_str() {
+            "rad" => Angle::from_radians(parse_number(number)?),
+            _ => return Err(unknown_unit(&unit)),
+        })
     }
 }
 
+implement_debug! { Angle, radians }
+
 implement_measurement! { Angle }
 
+/// Keeps a running total of an angle which may grow past a full turn, such as
+/// the position of an encoder or gimbal found by integrating angular
+/// velocity, as a number of whole turns and an angle within the current turn.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Angle, AngleAccumulator};
+///
+/// let mut encoder = AngleAccumulator::new();
+/// encoder.add(Angle::from_degrees(300.0));
+/// encoder.add(Angle::from_degrees(100.0));
+/// assert_eq!(encoder.turns(), 1);
+/// println!("{} degrees into the turn", encoder.wrapped().as_degrees());
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AngleAccumulator {
+    wrapped: Angle,
+    turns: i64,
+}
+
+impl AngleAccumulator {
+    /// Create a new AngleAccumulator starting at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the given Angle, which may be negative or more than a full turn,
+    /// to the total
+    pub fn add(&mut self, delta: Angle) {
+        let full_turn = 2.0 * ::PI;
+        let sum = self.wrapped.as_radians() + delta.as_radians();
+        #[cfg(feature = "std")]
+        let turns = (sum / full_turn).floor();
+        #[cfg(not(feature = "std"))]
+        let turns = libm::floor(sum / full_turn);
+        // Rounding can leave a full turn over when `sum` is just below a
+        // multiple of one.
+        let mut wrapped = sum - turns * full_turn;
+        let mut turns = turns as i64;
+        if wrapped >= full_turn {
+            wrapped -= full_turn;
+            turns += 1;
+        }
+        self.turns += turns;
+        self.wrapped = Angle::from_radians(wrapped);
+    }
+
+    /// The total Angle accumulated, including whole turns
+    pub fn total(&self) -> Angle {
+        Angle::from_radians(self.turns as f64 * 2.0 * ::PI + self.wrapped.as_radians())
+    }
+
+    /// The Angle within the current turn, from 0 up to (but not including)
+    /// 360 degrees
+    pub fn wrapped(&self) -> Angle {
+        self.wrapped
+    }
+
+    /// The number of whole turns accumulated, which is negative if the total
+    /// is below zero
+    pub fn turns(&self) -> i64 {
+        self.turns
+    }
+}
+
 #[cfg(test)]
 mod test {
     use angle::*;
@@ -148,6 +279,61 @@ mod test {
         assert_almost_eq(r2, 180.0);
     }
 
+    #[test]
+    fn accumulator() {
+        let mut acc = AngleAccumulator::new();
+        for _ in 0..10 {
+            acc.add(Angle::from_degrees(100.0));
+        }
+        assert_eq!(acc.turns(), 2);
+        assert_almost_eq(acc.wrapped().as_degrees(), 280.0);
+        assert_almost_eq(acc.total().as_degrees(), 1000.0);
+
+        acc.add(Angle::from_degrees(-1300.0));
+        assert_eq!(acc.turns(), -1);
+        assert_almost_eq(acc.wrapped().as_degrees(), 60.0);
+        assert_almost_eq(acc.total().as_degrees(), -300.0);
+    }
+
+    #[test]
+    fn grade_percent() {
+        let i1 = Angle::from_grade_percent(100.0);
+        let r1 = i1.as_degrees();
+        let i2 = Angle::from_degrees(45.0);
+        let r2 = i2.as_grade_percent();
+        assert_almost_eq(r1, 45.0);
+        assert_almost_eq(r2, 100.0);
+    }
+
+    #[test]
+    fn slope_ratio() {
+        let i1 = Angle::from_slope_ratio(1.0, 1.0);
+        let r1 = i1.as_degrees();
+        let i2 = Angle::from_slope_ratio(1.0, 12.0);
+        let (rise, run) = i2.as_slope_ratio();
+        assert_almost_eq(r1, 45.0);
+        assert_almost_eq(rise / run, 1.0 / 12.0);
+    }
+
+    #[test]
+    fn arc_length() {
+        let r = Length::from_meters(1.0);
+        assert_almost_eq(
+            Angle::from_degrees(360.0).arc_length(r).as_meters(),
+            2.0 * PI,
+        );
+        assert_almost_eq(
+            Angle::from_degrees(90.0).arc_length(r).as_meters(),
+            PI / 2.0,
+        );
+    }
+
+    #[test]
+    fn from_arc_length() {
+        let a = Angle::from_arc_length(Length::from_meters(3.0), Length::from_meters(2.0));
+        assert_almost_eq(a.as_radians(), 1.5);
+    }
+
     #[test]
     #[cfg(feature = "from_str")]
     fn angle_from_str() {
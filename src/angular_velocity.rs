@@ -1,12 +1,22 @@
 //! Types and constants for handling speed of rotation (angular velocity)
 
 use super::measurement::*;
+use length::Length;
 #[cfg(feature = "from_str")]
-use regex::Regex;
+use parse::{parse_number, split_number_unit, unknown_unit, ParseMeasurementError};
+use speed::Speed;
 #[cfg(feature = "from_str")]
 use std::str::FromStr;
 use PI;
 
+/// Units supported by [`AngularVelocity`], as (symbol, long name, number of radians per second
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("rad/s", "radians per second", 1.0),
+    ("rpm", "revolutions per minute", 2.0 * PI / 60.0),
+    ("Hz", "hertz", 2.0 * PI),
+];
+
 /// The 'AngularVelocity' struct can be used to deal with angular velocities in a common way.
 ///
 /// # Example
@@ -19,7 +29,8 @@ use PI;
 /// let sparks_per_second = (engine_speed.as_hertz() / 2.0) * cylinders;
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct AngularVelocity {
     radians_per_second: f64,
 }
@@ -55,9 +66,17 @@ impl AngularVelocity {
     pub fn as_hertz(&self) -> f64 {
         self.radians_per_second / (2.0 * PI)
     }
+
+    /// Calculate the ground speed of a wheel with the given radius turning at
+    /// this AngularVelocity, assuming it rolls without slipping (ω × r)
+    pub fn ground_speed(&self, wheel_radius: Length) -> Speed {
+        Speed::from_meters_per_second(self.radians_per_second * wheel_radius.as_meters())
+    }
 }
 
 impl Measurement for AngularVelocity {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.radians_per_second
     }
@@ -69,11 +88,15 @@ impl Measurement for AngularVelocity {
     fn get_base_units_name(&self) -> &'static str {
         "rad/s"
     }
+
+    fn quantity_name() -> &'static str {
+        "AngularVelocity"
+    }
 }
 
 #[cfg(feature = "from_str")]
 impl FromStr for AngularVelocity {
-    type Err = std::num::ParseFloatError;
+    type Err = ParseMeasurementError;
 
     /// Create a new AngularVelocity from a string
     /// Plain numbers in string are considered to be radians per second
@@ -82,25 +105,19 @@ impl FromStr for AngularVelocity {
             return Ok(AngularVelocity::from_radians_per_second(0.0));
         }
 
-        let re = Regex::new(r"(?i)\s*([0-9.]*)\s?([radspmhz/]{1,5})\s*$").unwrap();
-        if let Some(caps) = re.captures(val) {
-            let float_val = caps.get(1).unwrap().as_str();
-            return Ok(
-                match caps.get(2).unwrap().as_str().to_lowercase().as_str() {
-                    "rad/s" => AngularVelocity::from_radians_per_second(float_val.parse::<f64>()?),
-                    "rpm" => AngularVelocity::from_rpm(float_val.parse::<f64>()?),
-                    "hz" => AngularVelocity::from_hertz(float_val.parse::<f64>()?),
-                    _ => AngularVelocity::from_radians_per_second(val.parse::<f64>()?),
-                },
-            );
-        }
-
-        Ok(AngularVelocity::from_radians_per_second(
-            val.parse::<f64>()?,
-        ))
+        let (number, unit) = split_number_unit(val);
+        Ok(match unit.to_lowercase().as_str() {
+            "" => AngularVelocity::from_radians_per_second(parse_number(number)?),
+            "rad/s" => AngularVelocity::from_radians_per_second(parse_number(number)?),
+            "rpm" => AngularVelocity::from_rpm(parse_number(number)?),
+            "hz" => AngularVelocity::from_hertz(parse_number(number)?),
+            _ => return Err(unknown_unit(&unit)),
+        })
     }
 }
 
+implement_debug! { AngularVelocity, radians_per_second }
+
 implement_measurement! { AngularVelocity }
 
 #[cfg(test)]
@@ -118,6 +135,13 @@ mod test {
         assert_almost_eq(r2, 954.929659642538);
     }
 
+    #[test]
+    fn ground_speed() {
+        let w = AngularVelocity::from_radians_per_second(10.0);
+        let v = w.ground_speed(Length::from_meters(0.3));
+        assert_almost_eq(v.as_meters_per_second(), 3.0);
+    }
+
     #[test]
     #[cfg(feature = "from_str")]
     fn empty_str() {
@@ -2,14 +2,49 @@
 
 use super::length;
 use super::measurement::*;
+use force::Force;
+use length::Length;
 #[cfg(feature = "from_str")]
-use regex::Regex;
+use parse::{parse_number, split_number_unit, unknown_unit, ParseMeasurementError};
+use pressure::Pressure;
 #[cfg(feature = "from_str")]
 use std::str::FromStr;
+use PI;
 
 /// Number of acres in a square meter
 const SQUARE_METER_ACRE_FACTOR: f64 = 1.0 / 4046.86;
 
+/// Units supported by [`Area`], as (symbol, long name, number of square meters
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("mm\u{00B2}", "square millimeters", 1e-6),
+    ("cm\u{00B2}", "square centimeters", 1e-4),
+    ("m\u{00B2}", "square meters", 1.0),
+    ("ha", "hectares", 1e4),
+    ("km\u{00B2}", "square kilometers", 1e6),
+    (
+        "in\u{00B2}",
+        "square inches",
+        1.0 / (length::METER_INCH_FACTOR * length::METER_INCH_FACTOR),
+    ),
+    (
+        "ft\u{00B2}",
+        "square feet",
+        1.0 / (length::METER_FEET_FACTOR * length::METER_FEET_FACTOR),
+    ),
+    (
+        "yd\u{00B2}",
+        "square yards",
+        1.0 / (length::METER_YARD_FACTOR * length::METER_YARD_FACTOR),
+    ),
+    ("ac", "acres", 1.0 / SQUARE_METER_ACRE_FACTOR),
+    (
+        "mi\u{00B2}",
+        "square miles",
+        1.0 / (length::METER_MILE_FACTOR * length::METER_MILE_FACTOR),
+    ),
+];
+
 /// The `Area` struct can be used to deal with areas in a common way.
 /// Common metric and imperial units are supported.
 ///
@@ -23,7 +58,8 @@ const SQUARE_METER_ACRE_FACTOR: f64 = 1.0 / 4046.86;
 /// println!("There are {} acres in a football field.", acres);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Area {
     square_meters: f64,
 }
@@ -165,6 +201,74 @@ impl Area {
         )
     }
 
+    /// Create new area of a circle with the given radius (πr²)
+    pub fn of_circle(radius: Length) -> Self {
+        let r = radius.as_meters();
+        Self::from_square_meters(PI * r * r)
+    }
+
+    /// Create new area of a rectangle with the given width and height
+    pub fn of_rectangle(width: Length, height: Length) -> Self {
+        Self::from_square_meters(width.as_meters() * height.as_meters())
+    }
+
+    /// Create new area of a triangle with the given base and height (½bh)
+    pub fn of_triangle(base: Length, height: Length) -> Self {
+        Self::from_square_meters(0.5 * base.as_meters() * height.as_meters())
+    }
+
+    /// Create new area of an ellipse with the given semi-major and semi-minor
+    /// axes (πab)
+    pub fn of_ellipse(semi_major: Length, semi_minor: Length) -> Self {
+        Self::from_square_meters(PI * semi_major.as_meters() * semi_minor.as_meters())
+    }
+
+    /// Create new area of the surface of a sphere with the given radius (4πr²)
+    pub fn sphere_surface(radius: Length) -> Self {
+        let r = radius.as_meters();
+        Self::from_square_meters(4.0 * PI * r * r)
+    }
+
+    /// Create new area of the surface of a closed cylinder with the given
+    /// radius and height, including both ends (2πr² + 2πrh)
+    pub fn cylinder_surface(radius: Length, height: Length) -> Self {
+        let r = radius.as_meters();
+        Self::from_square_meters(2.0 * PI * r * (r + height.as_meters()))
+    }
+
+    /// Create new area of the surface of a cube with the given side (6s²)
+    pub fn cube_surface(side: Length) -> Self {
+        let s = side.as_meters();
+        Self::from_square_meters(6.0 * s * s)
+    }
+
+    /// Create a new Area just large enough to spread the given Force so the
+    /// Pressure stays at or below `max_pressure` (F / P), e.g. to size a
+    /// footing. Returns `None` if the pressure is not positive.
+    pub fn required_for(force: Force, max_pressure: Pressure) -> Option<Self> {
+        if max_pressure.as_pascals() > 0.0 {
+            Some(Self::from_square_meters(
+                force.as_newtons() / max_pressure.as_pascals(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Calculate the side Length of a square with this Area (√A), e.g. as a
+    /// characteristic dimension of a plot of land
+    #[cfg(feature = "std")]
+    pub fn side_length(&self) -> Length {
+        Length::from_meters(self.square_meters.sqrt())
+    }
+
+    /// Calculate the side Length of a square with this Area (√A), e.g. as a
+    /// characteristic dimension of a plot of land
+    #[cfg(not(feature = "std"))]
+    pub fn side_length(&self) -> Length {
+        Length::from_meters(libm::sqrt(self.square_meters))
+    }
+
     /// Convert this Area to a floating point value in Square Nanometers
     pub fn as_square_nanometers(&self) -> f64 {
         self.square_meters * (length::METER_NANOMETER_FACTOR * length::METER_NANOMETER_FACTOR)
@@ -277,6 +381,8 @@ impl Area {
 }
 
 impl Measurement for Area {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.square_meters
     }
@@ -303,11 +409,15 @@ impl Measurement for Area {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Area"
+    }
 }
 
 #[cfg(feature = "from_str")]
 impl FromStr for Area {
-    type Err = std::num::ParseFloatError;
+    type Err = ParseMeasurementError;
 
     /// Create a new Area from a string
     /// Plain numbers in string are considered to be square meters
@@ -316,45 +426,30 @@ impl FromStr for Area {
             return Ok(Area::from_square_meters(0.0));
         }
 
-        let re = Regex::new(r"(?i)\s*([0-9.]*)\s?([a-z2\u{00B2}\u{00B5} ]{1,5})\s*$").unwrap();
-        if let Some(caps) = re.captures(val) {
-            println!("{:?}", caps);
-            let float_val = caps.get(1).unwrap().as_str();
-            return Ok(
-                match caps.get(2).unwrap().as_str().trim().to_lowercase().as_str() {
-                    "nm\u{00B2}" | "nm2" => Area::from_square_nanometers(float_val.parse::<f64>()?),
-                    "\u{00B5}m\u{00B2}" | "\u{00B5}m2" | "um\u{00B2}" | "um2" => {
-                        Area::from_square_micrometers(float_val.parse::<f64>()?)
-                    }
-                    "mm\u{00B2}" | "mm2" => {
-                        Area::from_square_millimeters(float_val.parse::<f64>()?)
-                    }
-                    "cm\u{00B2}" | "cm2" => {
-                        Area::from_square_centimeters(float_val.parse::<f64>()?)
-                    }
-                    "dm\u{00B2}" | "dm2" => Area::from_square_decimeters(float_val.parse::<f64>()?),
-                    "m\u{00B2}" | "m2" => Area::from_square_meters(float_val.parse::<f64>()?),
-                    "km\u{00B2}" | "km2" => Area::from_square_kilometers(float_val.parse::<f64>()?),
-                    "ha" | "hm\u{00B2}" | "hm2" => Area::from_hectares(float_val.parse::<f64>()?),
-                    "acre" | "ac" => Area::from_acres(float_val.parse::<f64>()?),
-                    "ft\u{00B2}" | "ft2" | "sq ft" => {
-                        Area::from_square_feet(float_val.parse::<f64>()?)
-                    }
-                    "yd\u{00B2}" | "yd2" | "sq yd" => {
-                        Area::from_square_yards(float_val.parse::<f64>()?)
-                    }
-                    "mi\u{00B2}" | "mi2" | "sq mi" => {
-                        Area::from_square_miles(float_val.parse::<f64>()?)
-                    }
-                    _ => Area::from_square_meters(val.parse::<f64>()?),
-                },
-            );
-        }
-
-        Ok(Area::from_square_meters(val.parse::<f64>()?))
+        let (number, unit) = split_number_unit(val);
+        Ok(match unit.to_lowercase().as_str() {
+            "" => Area::from_square_meters(parse_number(number)?),
+            "nm\u{00B2}" | "nm2" => Area::from_square_nanometers(parse_number(number)?),
+            "\u{00B5}m\u{00B2}" | "\u{00B5}m2" | "um\u{00B2}" | "um2" => {
+                Area::from_square_micrometers(parse_number(number)?)
+            }
+            "mm\u{00B2}" | "mm2" => Area::from_square_millimeters(parse_number(number)?),
+            "cm\u{00B2}" | "cm2" => Area::from_square_centimeters(parse_number(number)?),
+            "dm\u{00B2}" | "dm2" => Area::from_square_decimeters(parse_number(number)?),
+            "m\u{00B2}" | "m2" => Area::from_square_meters(parse_number(number)?),
+            "km\u{00B2}" | "km2" => Area::from_square_kilometers(parse_number(number)?),
+            "ha" | "hm\u{00B2}" | "hm2" => Area::from_hectares(parse_number(number)?),
+            "acre" | "ac" => Area::from_acres(parse_number(number)?),
+            "ft\u{00B2}" | "ft2" | "sq ft" => Area::from_square_feet(parse_number(number)?),
+            "yd\u{00B2}" | "yd2" | "sq yd" => Area::from_square_yards(parse_number(number)?),
+            "mi\u{00B2}" | "mi2" | "sq mi" => Area::from_square_miles(parse_number(number)?),
+            _ => return Err(unknown_unit(&unit)),
+        })
     }
 }
 
+implement_debug! { Area, square_meters }
+
 implement_measurement! { Area }
 
 #[cfg(test)]
@@ -582,6 +677,68 @@ mod test {
         assert_almost_eq(r2, 258998704.7);
     }
 
+    #[test]
+    fn of_circle() {
+        let a = Area::of_circle(Length::from_meters(1.0));
+        assert_almost_eq(a.as_square_meters(), PI);
+    }
+
+    #[test]
+    fn of_rectangle() {
+        let a = Area::of_rectangle(Length::from_meters(2.0), Length::from_centimeters(50.0));
+        assert_almost_eq(a.as_square_meters(), 1.0);
+    }
+
+    #[test]
+    fn of_triangle() {
+        let a = Area::of_triangle(Length::from_meters(3.0), Length::from_meters(4.0));
+        assert_almost_eq(a.as_square_meters(), 6.0);
+    }
+
+    #[test]
+    fn of_ellipse() {
+        let a = Area::of_ellipse(Length::from_meters(2.0), Length::from_meters(1.0));
+        assert_almost_eq(a.as_square_meters(), 2.0 * PI);
+        let b = Area::of_ellipse(Length::from_meters(1.0), Length::from_meters(1.0));
+        assert_eq!(b, Area::of_circle(Length::from_meters(1.0)));
+    }
+
+    #[test]
+    fn sphere_surface() {
+        let a = Area::sphere_surface(Length::from_meters(1.0));
+        assert_almost_eq(a.as_square_meters(), 12.566371);
+    }
+
+    #[test]
+    fn required_for() {
+        let a = Area::required_for(
+            Force::from_newtons(1000.0),
+            Pressure::from_kilopascals(50.0),
+        );
+        assert_almost_eq(a.unwrap().as_square_meters(), 0.02);
+        let none = Area::required_for(Force::from_newtons(1000.0), Pressure::from_pascals(0.0));
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn side_length() {
+        let a = Area::from_square_meters(9.0);
+        assert_almost_eq(a.side_length().as_meters(), 3.0);
+        assert_almost_eq(Area::from_hectares(1.0).side_length().as_meters(), 100.0);
+    }
+
+    #[test]
+    fn cylinder_surface() {
+        let a = Area::cylinder_surface(Length::from_meters(1.0), Length::from_meters(2.0));
+        assert_almost_eq(a.as_square_meters(), 6.0 * PI);
+    }
+
+    #[test]
+    fn cube_surface() {
+        let a = Area::cube_surface(Length::from_centimeters(10.0));
+        assert_almost_eq(a.as_square_centimeters(), 600.0);
+    }
+
     #[test]
     #[cfg(feature = "from_str")]
     fn empty_str() {
@@ -0,0 +1,241 @@
+//! Types and constants for handling area density (mass per unit area).
+
+use super::length;
+use super::mass;
+use super::measurement::*;
+
+/// Number of kilograms per square meter in one pound per square foot
+const KGSM_LBSF_FACTOR: f64 =
+    length::METER_FEET_FACTOR * length::METER_FEET_FACTOR / mass::KILOGRAM_POUNDS_FACTOR;
+/// Number of kilograms per square meter in one ounce per square yard
+const KGSM_OZSY_FACTOR: f64 =
+    length::METER_YARD_FACTOR * length::METER_YARD_FACTOR / mass::KILOGRAM_OUNCES_FACTOR;
+
+/// Units supported by [`AreaDensity`], as (symbol, long name, number of
+/// kilograms per square meter in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("g/m\u{00B2}", "grams per square meter", 1e-3),
+    ("kg/m\u{00B2}", "kilograms per square meter", 1.0),
+    ("oz/yd\u{00B2}", "ounces per square yard", KGSM_OZSY_FACTOR),
+    ("lb/ft\u{00B2}", "pounds per square foot", KGSM_LBSF_FACTOR),
+];
+
+/// The `AreaDensity` struct can be used to deal with area densities (mass per
+/// unit area) in a common way, such as the weight of paper (GSM) or fabric, or
+/// a snow load.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Area, AreaDensity, Mass};
+///
+/// let paper = AreaDensity::from_grams_per_square_meter(80.0);
+/// let sheet = Area::from_square_meters(0.210 * 0.297);
+/// let mass: Mass = paper * sheet;
+/// println!("A sheet of A4 paper weighs {} grams.", mass.as_grams());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct AreaDensity {
+    kilograms_per_square_meter: f64,
+}
+
+impl AreaDensity {
+    /// Create a new AreaDensity from a floating point value in kilograms per square meter
+    pub fn from_kilograms_per_square_meter(kilograms_per_square_meter: f64) -> Self {
+        AreaDensity {
+            kilograms_per_square_meter,
+        }
+    }
+
+    /// Create a new AreaDensity from a floating point value in grams per square meter (GSM)
+    pub fn from_grams_per_square_meter(grams_per_square_meter: f64) -> Self {
+        Self::from_kilograms_per_square_meter(grams_per_square_meter / 1000.0)
+    }
+
+    /// Create a new AreaDensity from a floating point value in ounces per square yard
+    pub fn from_ounces_per_square_yard(ounces_per_square_yard: f64) -> Self {
+        Self::from_kilograms_per_square_meter(ounces_per_square_yard * KGSM_OZSY_FACTOR)
+    }
+
+    /// Create a new AreaDensity from a floating point value in pounds per square foot
+    pub fn from_pounds_per_square_foot(pounds_per_square_foot: f64) -> Self {
+        Self::from_kilograms_per_square_meter(pounds_per_square_foot * KGSM_LBSF_FACTOR)
+    }
+
+    /// Convert this AreaDensity to a floating point value in kilograms per square meter
+    pub fn as_kilograms_per_square_meter(&self) -> f64 {
+        self.kilograms_per_square_meter
+    }
+
+    /// Convert this AreaDensity to a floating point value in grams per square meter (GSM)
+    pub fn as_grams_per_square_meter(&self) -> f64 {
+        self.kilograms_per_square_meter * 1000.0
+    }
+
+    /// Convert this AreaDensity to a floating point value in ounces per square yard
+    pub fn as_ounces_per_square_yard(&self) -> f64 {
+        self.kilograms_per_square_meter / KGSM_OZSY_FACTOR
+    }
+
+    /// Convert this AreaDensity to a floating point value in pounds per square foot
+    pub fn as_pounds_per_square_foot(&self) -> f64 {
+        self.kilograms_per_square_meter / KGSM_LBSF_FACTOR
+    }
+}
+
+impl Measurement for AreaDensity {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.kilograms_per_square_meter
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_kilograms_per_square_meter(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "kg/m\u{00B2}"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [("g/m\u{00B2}", 1e-3), ("kg/m\u{00B2}", 1e0)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "AreaDensity"
+    }
+}
+
+implement_debug! { AreaDensity, kilograms_per_square_meter }
+
+implement_measurement! { AreaDensity }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use area::Area;
+    use mass::Mass;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn grams_per_square_meter() {
+        let i1 = AreaDensity::from_grams_per_square_meter(80.0);
+        let r1 = i1.as_kilograms_per_square_meter();
+        let i2 = AreaDensity::from_kilograms_per_square_meter(0.12);
+        let r2 = i2.as_grams_per_square_meter();
+        assert_almost_eq(r1, 0.08);
+        assert_almost_eq(r2, 120.0);
+    }
+
+    #[test]
+    fn ounces_per_square_yard() {
+        let i1 = AreaDensity::from_ounces_per_square_yard(1.0);
+        let r1 = i1.as_grams_per_square_meter();
+        let i2 = AreaDensity::from_grams_per_square_meter(200.0);
+        let r2 = i2.as_ounces_per_square_yard();
+        assert_almost_eq(r1, 33.905747);
+        assert_almost_eq(r2, 5.898705);
+    }
+
+    #[test]
+    fn pounds_per_square_foot() {
+        let i1 = AreaDensity::from_pounds_per_square_foot(1.0);
+        let r1 = i1.as_kilograms_per_square_meter();
+        let i2 = AreaDensity::from_kilograms_per_square_meter(100.0);
+        let r2 = i2.as_pounds_per_square_foot();
+        assert_almost_eq(r1, 4.882428);
+        assert_almost_eq(r2, 20.481614);
+    }
+
+    #[test]
+    fn paper_mass() {
+        let paper = AreaDensity::from_grams_per_square_meter(80.0);
+        let a = paper * Area::from_square_meters(1.0);
+        let b = Area::from_square_meters(1.0) * paper;
+        assert_almost_eq(a.as_grams(), 80.0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn mass_over_area() {
+        let d: AreaDensity = Mass::from_kilograms(150.0) / Area::from_square_meters(2.0);
+        assert_almost_eq(d.as_kilograms_per_square_meter(), 75.0);
+        let a: Area = Mass::from_kilograms(150.0) / d;
+        assert_almost_eq(a.as_square_meters(), 2.0);
+    }
+
+    #[test]
+    fn appropriate_units() {
+        let d = AreaDensity::from_grams_per_square_meter(80.0);
+        assert_eq!(d.get_appropriate_units().0, "g/m\u{00B2}");
+        let d = AreaDensity::from_kilograms_per_square_meter(8.0);
+        assert_eq!(d.get_appropriate_units().0, "kg/m\u{00B2}");
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = AreaDensity::from_kilograms_per_square_meter(2.0);
+        let b = AreaDensity::from_kilograms_per_square_meter(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_kilograms_per_square_meter(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = AreaDensity::from_kilograms_per_square_meter(2.0);
+        let b = AreaDensity::from_kilograms_per_square_meter(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_kilograms_per_square_meter(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = AreaDensity::from_kilograms_per_square_meter(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_kilograms_per_square_meter(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = AreaDensity::from_kilograms_per_square_meter(2.0);
+        let b = AreaDensity::from_kilograms_per_square_meter(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_kilograms_per_square_meter(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = AreaDensity::from_kilograms_per_square_meter(2.0);
+        let b = AreaDensity::from_kilograms_per_square_meter(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = AreaDensity::from_kilograms_per_square_meter(2.0);
+        let b = AreaDensity::from_kilograms_per_square_meter(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = AreaDensity::from_kilograms_per_square_meter(2.0);
+        let b = AreaDensity::from_kilograms_per_square_meter(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
@@ -0,0 +1,187 @@
+//! Types and constants for handling electrical capacitance.
+
+use super::measurement::*;
+
+/// Units supported by [`Capacitance`], as (symbol, long name, number of
+/// farads in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("pF", "picofarads", 1e-12),
+    ("nF", "nanofarads", 1e-9),
+    ("\u{00B5}F", "microfarads", 1e-6),
+    ("mF", "millifarads", 1e-3),
+    ("F", "farads", 1.0),
+];
+
+/// The `Capacitance` struct can be used to deal with electrical capacitance in
+/// a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::Capacitance;
+///
+/// let c = Capacitance::from_nanofarads(100.0);
+/// println!("A 100 nF capacitor is {} \u{00B5}F", c.as_microfarads());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Capacitance {
+    farads: f64,
+}
+
+impl Capacitance {
+    /// Create a new Capacitance from a floating point value in farads
+    pub fn from_farads(farads: f64) -> Self {
+        Capacitance { farads }
+    }
+
+    /// Create a new Capacitance from a floating point value in microfarads
+    pub fn from_microfarads(microfarads: f64) -> Self {
+        Self::from_farads(microfarads / 1e6)
+    }
+
+    /// Create a new Capacitance from a floating point value in nanofarads
+    pub fn from_nanofarads(nanofarads: f64) -> Self {
+        Self::from_farads(nanofarads / 1e9)
+    }
+
+    /// Create a new Capacitance from a floating point value in picofarads
+    pub fn from_picofarads(picofarads: f64) -> Self {
+        Self::from_farads(picofarads / 1e12)
+    }
+
+    /// Convert this Capacitance into a floating point value in farads
+    pub fn as_farads(&self) -> f64 {
+        self.farads
+    }
+
+    /// Convert this Capacitance into a floating point value in microfarads
+    pub fn as_microfarads(&self) -> f64 {
+        self.farads * 1e6
+    }
+
+    /// Convert this Capacitance into a floating point value in nanofarads
+    pub fn as_nanofarads(&self) -> f64 {
+        self.farads * 1e9
+    }
+
+    /// Convert this Capacitance into a floating point value in picofarads
+    pub fn as_picofarads(&self) -> f64 {
+        self.farads * 1e12
+    }
+}
+
+impl Measurement for Capacitance {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.farads
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_farads(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "F"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("pF", 1e-12),
+            ("nF", 1e-9),
+            ("\u{00B5}F", 1e-6),
+            ("mF", 1e-3),
+            ("F", 1e0),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "Capacitance"
+    }
+}
+
+implement_debug! { Capacitance, farads }
+
+implement_measurement! { Capacitance }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn microfarads() {
+        let i1 = Capacitance::from_microfarads(4.7);
+        let r1 = i1.as_nanofarads();
+        let i2 = Capacitance::from_picofarads(22_000.0);
+        let r2 = i2.as_nanofarads();
+        assert_almost_eq(r1, 4700.0);
+        assert_almost_eq(r2, 22.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Capacitance::from_farads(2.0);
+        let b = Capacitance::from_farads(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_farads(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = Capacitance::from_farads(2.0);
+        let b = Capacitance::from_farads(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_farads(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = Capacitance::from_farads(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_farads(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = Capacitance::from_farads(2.0);
+        let b = Capacitance::from_farads(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_farads(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = Capacitance::from_farads(2.0);
+        let b = Capacitance::from_farads(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = Capacitance::from_farads(2.0);
+        let b = Capacitance::from_farads(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Capacitance::from_farads(2.0);
+        let b = Capacitance::from_farads(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
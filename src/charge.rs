@@ -0,0 +1,224 @@
+//! Types and constants for handling electric charge.
+
+use super::measurement::*;
+use current::Current;
+use energy::Energy;
+use time;
+use voltage::Voltage;
+
+/// Units supported by [`Charge`], as (symbol, long name, number of coulombs
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("C", "coulombs", 1.0),
+    ("mAh", "milliampere hours", 3.6),
+    ("Ah", "ampere hours", 3600.0),
+];
+
+/// The `Charge` struct can be used to deal with electric charge in a common
+/// way, such as the capacity of a battery.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Charge, Current};
+///
+/// let battery = Charge::from_milliampere_hours(2000.0);
+/// let runtime = battery.runtime_at_current(Current::from_milliamperes(500.0));
+/// println!("The battery lasts {:?}.", runtime);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Charge {
+    coulombs: f64,
+}
+
+impl Charge {
+    /// Create a new Charge from a floating point value in coulombs
+    pub fn from_coulombs(coulombs: f64) -> Self {
+        Charge { coulombs }
+    }
+
+    /// Create a new Charge from a floating point value in milliampere hours
+    pub fn from_milliampere_hours(milliampere_hours: f64) -> Self {
+        Self::from_coulombs(milliampere_hours * 3.6)
+    }
+
+    /// Create a new Charge from a floating point value in ampere hours
+    pub fn from_ampere_hours(ampere_hours: f64) -> Self {
+        Self::from_coulombs(ampere_hours * 3600.0)
+    }
+
+    /// Convert this Charge into a floating point value in coulombs
+    pub fn as_coulombs(&self) -> f64 {
+        self.coulombs
+    }
+
+    /// Convert this Charge into a floating point value in milliampere hours
+    pub fn as_milliampere_hours(&self) -> f64 {
+        self.coulombs / 3.6
+    }
+
+    /// Convert this Charge into a floating point value in ampere hours
+    pub fn as_ampere_hours(&self) -> f64 {
+        self.coulombs / 3600.0
+    }
+
+    /// Calculate how long this Charge lasts when drawn at the given constant
+    /// Current (Q / I). Returns `None` if the draw is not positive, as the
+    /// runtime would be infinite.
+    pub fn runtime_at_current(&self, draw: Current) -> Option<time::Duration> {
+        if draw.as_amperes() > 0.0 {
+            Some(time::Duration::from_base_units(
+                self.coulombs / draw.as_amperes(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Calculate the Energy stored in this Charge at the given nominal
+    /// Voltage (Q × V), e.g. to convert a battery's Ah rating to Wh
+    pub fn energy_at_voltage(&self, v: Voltage) -> Energy {
+        Energy::from_joules(self.coulombs * v.as_volts())
+    }
+}
+
+impl Measurement for Charge {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.coulombs
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_coulombs(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "C"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("pC", 1e-12),
+            ("nC", 1e-9),
+            ("\u{00B5}C", 1e-6),
+            ("mC", 1e-3),
+            ("C", 1e0),
+            ("kC", 1e3),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "Charge"
+    }
+}
+
+implement_debug! { Charge, coulombs }
+
+implement_measurement! { Charge }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn ampere_hours() {
+        let i1 = Charge::from_ampere_hours(2.0);
+        let r1 = i1.as_coulombs();
+        let i2 = Charge::from_coulombs(7200.0);
+        let r2 = i2.as_milliampere_hours();
+        assert_almost_eq(r1, 7200.0);
+        assert_almost_eq(r2, 2000.0);
+    }
+
+    #[test]
+    fn current_times_duration() {
+        let c: Charge = Current::from_amperes(2.0) * time::Duration::from_secs(3600);
+        assert_almost_eq(c.as_ampere_hours(), 2.0);
+        let i: Current = c / time::Duration::from_secs(7200);
+        assert_almost_eq(i.as_amperes(), 1.0);
+    }
+
+    #[test]
+    fn runtime_at_current() {
+        let battery = Charge::from_milliampere_hours(2000.0);
+        let runtime = battery.runtime_at_current(Current::from_milliamperes(500.0));
+        assert_eq!(runtime, Some(time::Duration::from_secs(4 * 3600)));
+        let runtime = battery.runtime_at_current(Current::from_amperes(0.0));
+        assert_eq!(runtime, None);
+    }
+
+    #[test]
+    fn energy_at_voltage() {
+        let battery = Charge::from_ampere_hours(10.0);
+        let energy = battery.energy_at_voltage(Voltage::from_volts(12.0));
+        assert_almost_eq(energy.as_watt_hours(), 120.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Charge::from_coulombs(2.0);
+        let b = Charge::from_coulombs(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_coulombs(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = Charge::from_coulombs(2.0);
+        let b = Charge::from_coulombs(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_coulombs(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = Charge::from_coulombs(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_coulombs(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = Charge::from_coulombs(2.0);
+        let b = Charge::from_coulombs(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_coulombs(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = Charge::from_coulombs(2.0);
+        let b = Charge::from_coulombs(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = Charge::from_coulombs(2.0);
+        let b = Charge::from_coulombs(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Charge::from_coulombs(2.0);
+        let b = Charge::from_coulombs(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
@@ -0,0 +1,277 @@
+//! Types and constants for handling concentrations given as a ratio, such as
+//! parts per million.
+
+use super::measurement::*;
+use amount_of_substance::AmountOfSubstance;
+use constants::MOLAR_GAS_CONSTANT;
+use mass::Mass;
+use mass_concentration::MassConcentration;
+use molar_mass::MolarMass;
+use pressure::Pressure;
+use temperature::Temperature;
+use volume::Volume;
+
+/// Units supported by [`Concentration`], as (symbol, long name, fraction in
+/// one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("ppt", "parts per trillion", 1e-12),
+    ("ppb", "parts per billion", 1e-9),
+    ("ppm", "parts per million", 1e-6),
+    ("%", "percent", 1e-2),
+];
+
+/// The `Concentration` struct can be used to deal with the amount of one
+/// substance in a mixture as a dimensionless fraction, such as the parts per
+/// million of a pollutant in air (by volume or mole fraction) or in water (by
+/// mass).
+///
+/// This is distinct from a [`MassConcentration`] such as mg/m³, which has
+/// units.
+///
+/// # Example
+///
+/// ```
+/// use measurements::Concentration;
+///
+/// let co2 = Concentration::from_ppm(420.0);
+/// println!("CO2 is {} of the air.", co2);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Concentration {
+    fraction: f64,
+}
+
+impl Concentration {
+    /// Create a new Concentration from a floating point value fraction (i.e. 0.0 to 1.0)
+    pub fn from_fraction(fraction: f64) -> Self {
+        Concentration { fraction }
+    }
+
+    /// Create a new Concentration from a floating point value in parts per million
+    pub fn from_ppm(ppm: f64) -> Self {
+        Self::from_fraction(ppm * 1e-6)
+    }
+
+    /// Create a new Concentration from a floating point value in parts per billion
+    pub fn from_ppb(ppb: f64) -> Self {
+        Self::from_fraction(ppb * 1e-9)
+    }
+
+    /// Create a new Concentration of a solute by mass, e.g. a pollutant in a
+    /// sample of water
+    pub fn by_mass(solute: Mass, mixture: Mass) -> Self {
+        Self::from_fraction(solute.as_kilograms() / mixture.as_kilograms())
+    }
+
+    /// Create a new Concentration of a gas by volume, e.g. a pollutant in a
+    /// sample of air
+    pub fn by_volume(solute: Volume, mixture: Volume) -> Self {
+        Self::from_fraction(solute.as_cubic_meters() / mixture.as_cubic_meters())
+    }
+
+    /// Create a new Concentration as the mole fraction of a substance in a
+    /// mixture. For an ideal gas, this is the same as the fraction by volume.
+    pub fn mole_fraction(solute: AmountOfSubstance, mixture: AmountOfSubstance) -> Self {
+        Self::from_fraction(solute.as_moles() / mixture.as_moles())
+    }
+
+    /// Convert this Concentration to a value expressed as a fraction (i.e. 0.0 to 1.0)
+    pub fn as_fraction(&self) -> f64 {
+        self.fraction
+    }
+
+    /// Convert this Concentration into a floating point value in parts per million
+    pub fn as_ppm(&self) -> f64 {
+        self.fraction * 1e6
+    }
+
+    /// Convert this Concentration into a floating point value in parts per billion
+    pub fn as_ppb(&self) -> f64 {
+        self.fraction * 1e9
+    }
+
+    /// Calculate the MassConcentration of a gas with the given MolarMass at
+    /// this Concentration by volume, at the given pressure and temperature
+    /// (treating the air as an ideal gas)
+    pub fn as_mass_concentration(
+        &self,
+        molar_mass: MolarMass,
+        pressure: Pressure,
+        temp: Temperature,
+    ) -> MassConcentration {
+        MassConcentration::from_kilograms_per_cubic_meter(
+            self.fraction * pressure.as_pascals() * molar_mass.as_kilograms_per_mole()
+                / (MOLAR_GAS_CONSTANT * temp.as_kelvin()),
+        )
+    }
+
+    /// Create a new Concentration by volume of a gas with the given MolarMass
+    /// from its MassConcentration, at the given pressure and temperature
+    /// (treating the air as an ideal gas)
+    pub fn from_mass_concentration(
+        mass_concentration: MassConcentration,
+        molar_mass: MolarMass,
+        pressure: Pressure,
+        temp: Temperature,
+    ) -> Self {
+        Self::from_fraction(
+            mass_concentration.as_kilograms_per_cubic_meter()
+                * MOLAR_GAS_CONSTANT
+                * temp.as_kelvin()
+                / (pressure.as_pascals() * molar_mass.as_kilograms_per_mole()),
+        )
+    }
+}
+
+impl Measurement for Concentration {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.fraction
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_fraction(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        ""
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // The base unit is a bare fraction with no symbol, so show zero in
+        // ppm rather than with an empty unit.
+        if self.fraction == 0.0 {
+            return ("ppm", 0.0);
+        }
+        // Smallest to Largest
+        let list = [("ppt", 1e-12), ("ppb", 1e-9), ("ppm", 1e-6), ("%", 1e-2)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "Concentration"
+    }
+}
+
+implement_debug! { Concentration, fraction }
+
+implement_measurement! { Concentration }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn ppm() {
+        assert_almost_eq(Concentration::from_ppm(1.0).as_fraction(), 1e-6);
+        assert_almost_eq(Concentration::from_ppb(1.0).as_fraction(), 1e-9);
+        assert_almost_eq(Concentration::from_ppm(2.5).as_ppb(), 2500.0);
+        assert_almost_eq(Concentration::from_fraction(1e-6).as_ppm(), 1.0);
+    }
+
+    #[test]
+    fn by_mass_and_volume() {
+        let c = Concentration::by_mass(Mass::from_milligrams(3.0), Mass::from_kilograms(1.0));
+        assert_almost_eq(c.as_ppm(), 3.0);
+        let c = Concentration::by_volume(Volume::from_milliliters(1.0), Volume::from_liters(2.0));
+        assert_almost_eq(c.as_ppm(), 500.0);
+        let c = Concentration::mole_fraction(
+            AmountOfSubstance::from_micromoles(4.0),
+            AmountOfSubstance::from_moles(1.0),
+        );
+        assert_almost_eq(c.as_ppm(), 4.0);
+    }
+
+    #[test]
+    fn mass_concentration() {
+        // 1 ppm of CO2 at 25 °C and 1 atm is about 1.8 mg/m³
+        let co2 = Concentration::from_ppm(1.0);
+        let pressure = Pressure::from_atmospheres(1.0);
+        let temp = Temperature::from_celsius(25.0);
+        let mass = co2.as_mass_concentration(MolarMass::carbon_dioxide(), pressure, temp);
+        assert!((mass.as_milligrams_per_cubic_meter() - 1.799).abs() < 0.001);
+        let back = Concentration::from_mass_concentration(
+            mass,
+            MolarMass::carbon_dioxide(),
+            pressure,
+            temp,
+        );
+        assert_almost_eq(back.as_ppm(), 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn display() {
+        assert_eq!(
+            format!("{:.0}", Concentration::from_ppm(420.0)),
+            "420\u{00A0}ppm"
+        );
+        assert_eq!(format!("{}", Concentration::from_ppm(0.0)), "0\u{00A0}ppm");
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Concentration::from_ppm(2.0);
+        let b = Concentration::from_ppm(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_ppm(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = Concentration::from_ppm(2.0);
+        let b = Concentration::from_ppm(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_ppm(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = Concentration::from_ppm(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_ppm(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = Concentration::from_ppm(2.0);
+        let b = Concentration::from_ppm(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_ppm(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = Concentration::from_ppm(2.0);
+        let b = Concentration::from_ppm(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = Concentration::from_ppm(2.0);
+        let b = Concentration::from_ppm(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Concentration::from_ppm(2.0);
+        let b = Concentration::from_ppm(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
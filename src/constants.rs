@@ -0,0 +1,80 @@
+//! Physical constants, as typed measurements where possible.
+//!
+//! Constants whose unit has no measurement type in this crate (such as the
+//! gravitational constant) are given as plain floating point values in SI
+//! units.
+
+use super::*;
+
+/// Speed of light in a vacuum, in m/s (exact)
+pub const SPEED_OF_LIGHT_METERS_PER_SECOND: f64 = 299_792_458.0;
+/// Standard acceleration due to gravity, in m/s² (exact)
+pub const STANDARD_GRAVITY_METERS_PER_SECOND_PER_SECOND: f64 = 9.80665;
+/// Standard atmospheric pressure, in Pa (exact)
+pub const STANDARD_ATMOSPHERE_PASCALS: f64 = 101_325.0;
+/// Mean radius of the Earth, in m
+pub const EARTH_MEAN_RADIUS_METERS: f64 = 6_371_008.8;
+/// Mass of the Earth, in kg
+pub const EARTH_MASS_KILOGRAMS: f64 = 5.9722e24;
+
+/// Newtonian constant of gravitation, in m³/(kg·s²)
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.674_30e-11;
+/// Boltzmann constant, in J/K (exact)
+pub const BOLTZMANN_CONSTANT: f64 = 1.380_649e-23;
+/// Avogadro constant, in 1/mol (exact)
+pub const AVOGADRO_CONSTANT: f64 = 6.022_140_76e23;
+/// Molar gas constant, in J/(mol·K)
+pub const MOLAR_GAS_CONSTANT: f64 = 8.314_462_618;
+
+/// The speed of light in a vacuum
+pub fn speed_of_light() -> Speed {
+    Speed::from_meters_per_second(SPEED_OF_LIGHT_METERS_PER_SECOND)
+}
+
+/// The standard acceleration due to gravity at the Earth's surface
+pub fn standard_gravity() -> Acceleration {
+    Acceleration::from_meters_per_second_per_second(STANDARD_GRAVITY_METERS_PER_SECOND_PER_SECOND)
+}
+
+/// The standard atmospheric pressure at sea level
+pub fn standard_atmosphere() -> Pressure {
+    Pressure::from_pascals(STANDARD_ATMOSPHERE_PASCALS)
+}
+
+/// The mean radius of the Earth
+pub fn earth_mean_radius() -> Length {
+    Length::from_meters(EARTH_MEAN_RADIUS_METERS)
+}
+
+/// The mass of the Earth
+pub fn earth_mass() -> Mass {
+    Mass::from_kilograms(EARTH_MASS_KILOGRAMS)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn speed_of_light_base_units() {
+        assert_almost_eq(speed_of_light().as_base_units(), 299_792_458.0);
+    }
+
+    #[test]
+    fn standard_gravity_base_units() {
+        assert_almost_eq(standard_gravity().as_base_units(), 9.80665);
+    }
+
+    #[test]
+    fn standard_atmosphere_base_units() {
+        assert_almost_eq(standard_atmosphere().as_base_units(), 101_325.0);
+        assert_almost_eq(standard_atmosphere().as_atmospheres(), 1.0);
+    }
+
+    #[test]
+    fn earth_base_units() {
+        assert_almost_eq(earth_mean_radius().as_base_units(), 6_371_008.8);
+        assert_almost_eq(earth_mass().as_base_units(), 5.9722e24);
+    }
+}
@@ -2,6 +2,15 @@
 
 use super::measurement::*;
 
+/// Units supported by [`Current`], as (symbol, long name, number of amperes
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("nA", "nanoamperes", 1e-9),
+    ("\u{00B5}A", "microamperes", 1e-6),
+    ("mA", "milliamperes", 1e-3),
+    ("A", "amperes", 1.0),
+];
+
 /// The `Current` struct can be used to deal with electric potential difference
 /// in a common way.
 ///
@@ -16,7 +25,8 @@ use super::measurement::*;
 /// println!("35 mA correspond to {} A or {} µA", a, u_a);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Current {
     amperes: f64,
 }
@@ -64,6 +74,8 @@ impl Current {
 }
 
 impl Measurement for Current {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.amperes
     }
@@ -94,8 +106,14 @@ impl Measurement for Current {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Current"
+    }
 }
 
+implement_debug! { Current, amperes }
+
 implement_measurement! { Current }
 
 #[cfg(test)]
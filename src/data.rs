@@ -1,6 +1,8 @@
 //! Types and constants for handling amounts of data (in octets, or bits).
 
 use super::measurement::*;
+use data_rate::DataRate;
+use time;
 
 // Constants
 const OCTET_BIT_FACTOR: f64 = 0.125;
@@ -17,6 +19,21 @@ const OCTET_MEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0;
 const OCTET_GIBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0;
 const OCTET_TEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0 * 1024.0;
 
+/// Units supported by [`Data`], as (symbol, long name, number of octets
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("bit", "bits", OCTET_BIT_FACTOR),
+    ("o", "octets", 1.0),
+    ("ko", "kilooctets", OCTET_KILOOCTET_FACTOR),
+    ("Mo", "megaoctets", OCTET_MEGAOCTET_FACTOR),
+    ("Go", "gigaoctets", OCTET_GIGAOCTET_FACTOR),
+    ("To", "teraoctets", OCTET_TERAOCTET_FACTOR),
+    ("KiB", "kibioctets", OCTET_KIBIOCTET_FACTOR),
+    ("MiB", "mebioctets", OCTET_MEBIOCTET_FACTOR),
+    ("GiB", "gibioctets", OCTET_GIBIOCTET_FACTOR),
+    ("TiB", "tebioctets", OCTET_TEBIOCTET_FACTOR),
+];
+
 /// The `Data` struct can be used to deal with computer information in a common way.
 /// Common legacy and SI units are supported.
 ///
@@ -30,7 +47,8 @@ const OCTET_TEBIOCTET_FACTOR: f64 = 1024.0 * 1024.0 * 1024.0 * 1024.0;
 /// println!("There are {} octets in that file.", octets);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Data {
     octets: f64,
 }
@@ -136,9 +154,22 @@ impl Data {
     pub fn as_tebioctets(&self) -> f64 {
         self.octets / OCTET_TEBIOCTET_FACTOR
     }
+
+    /// Calculate the time it takes to transfer this Data at the given
+    /// DataRate, e.g. how long a file takes to download. Returns `None` if the
+    /// rate is zero or negative.
+    pub fn transfer_time(&self, rate: DataRate) -> Option<time::Duration> {
+        if rate.as_octets_per_second() > 0.0 {
+            Some(*self / rate)
+        } else {
+            None
+        }
+    }
 }
 
 impl Measurement for Data {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.octets
     }
@@ -164,8 +195,14 @@ impl Measurement for Data {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Data"
+    }
 }
 
+implement_debug! { Data, octets }
+
 implement_measurement! { Data }
 
 #[cfg(test)]
@@ -283,6 +320,34 @@ mod test {
         assert_almost_eq(r2, 109951162777600.0);
     }
 
+    #[test]
+    fn transfer_time() {
+        let file = Data::from_gigaoctets(1.0);
+        let link = DataRate::from_megabits_per_second(100.0);
+        let t = file.transfer_time(link).unwrap();
+        assert_almost_eq(t.as_base_units(), 80.0);
+        assert_eq!(
+            file.transfer_time(DataRate::from_bits_per_second(0.0)),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", not(feature = "sig_fig_display")))]
+    fn display_grouped() {
+        let exbioctet = 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0;
+        let d = Data::from_octets(1_234_567.0 * exbioctet);
+        assert_eq!(format!("{}", d.display_grouped()), "1,234,567\u{00A0}EiB");
+        assert_eq!(
+            format!("{:.2}", d.display_grouped()),
+            "1,234,567.00\u{00A0}EiB"
+        );
+        let d = Data::from_octets(-123_456.5 * exbioctet);
+        assert_eq!(format!("{}", d.display_grouped()), "-123,456.5\u{00A0}EiB");
+        let d = Data::from_octets(12.0);
+        assert_eq!(format!("{}", d.display_grouped()), "12\u{00A0}octets");
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -0,0 +1,233 @@
+//! Types and constants for handling data rates (in octets, or bits, per second).
+
+use super::measurement::*;
+
+/// Units supported by [`DataRate`], as (symbol, long name, number of octets
+/// per second in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("bit/s", "bits per second", 0.125),
+    ("o/s", "octets per second", 1.0),
+    ("kbit/s", "kilobits per second", 125.0),
+    ("ko/s", "kilooctets per second", 1e3),
+    ("Mbit/s", "megabits per second", 125e3),
+    ("Mo/s", "megaoctets per second", 1e6),
+    ("Gbit/s", "gigabits per second", 125e6),
+    ("Go/s", "gigaoctets per second", 1e9),
+];
+
+/// The `DataRate` struct can be used to deal with the speed of a network
+/// link or a storage device in a common way. Link speeds are usually given in
+/// bits per second and file sizes in octets; both are supported.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Data, DataRate};
+///
+/// let file = Data::from_gigaoctets(1.0);
+/// let link = DataRate::from_megabits_per_second(100.0);
+/// println!("The download takes {:?}.", file.transfer_time(link));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct DataRate {
+    octets_per_second: f64,
+}
+
+impl DataRate {
+    /// Create a new DataRate from a floating point value in octets per second
+    pub fn from_octets_per_second(octets_per_second: f64) -> Self {
+        DataRate { octets_per_second }
+    }
+
+    /// Create a new DataRate from a floating point value in kilooctets per second
+    pub fn from_kilooctets_per_second(kilooctets_per_second: f64) -> Self {
+        Self::from_octets_per_second(kilooctets_per_second * 1e3)
+    }
+
+    /// Create a new DataRate from a floating point value in megaoctets per second
+    pub fn from_megaoctets_per_second(megaoctets_per_second: f64) -> Self {
+        Self::from_octets_per_second(megaoctets_per_second * 1e6)
+    }
+
+    /// Create a new DataRate from a floating point value in bits per second
+    pub fn from_bits_per_second(bits_per_second: f64) -> Self {
+        Self::from_octets_per_second(bits_per_second / 8.0)
+    }
+
+    /// Create a new DataRate from a floating point value in kilobits per second
+    pub fn from_kilobits_per_second(kilobits_per_second: f64) -> Self {
+        Self::from_bits_per_second(kilobits_per_second * 1e3)
+    }
+
+    /// Create a new DataRate from a floating point value in megabits per second
+    pub fn from_megabits_per_second(megabits_per_second: f64) -> Self {
+        Self::from_bits_per_second(megabits_per_second * 1e6)
+    }
+
+    /// Create a new DataRate from a floating point value in gigabits per second
+    pub fn from_gigabits_per_second(gigabits_per_second: f64) -> Self {
+        Self::from_bits_per_second(gigabits_per_second * 1e9)
+    }
+
+    /// Convert this DataRate into a floating point value in octets per second
+    pub fn as_octets_per_second(&self) -> f64 {
+        self.octets_per_second
+    }
+
+    /// Convert this DataRate into a floating point value in kilooctets per second
+    pub fn as_kilooctets_per_second(&self) -> f64 {
+        self.octets_per_second / 1e3
+    }
+
+    /// Convert this DataRate into a floating point value in megaoctets per second
+    pub fn as_megaoctets_per_second(&self) -> f64 {
+        self.octets_per_second / 1e6
+    }
+
+    /// Convert this DataRate into a floating point value in bits per second
+    pub fn as_bits_per_second(&self) -> f64 {
+        self.octets_per_second * 8.0
+    }
+
+    /// Convert this DataRate into a floating point value in kilobits per second
+    pub fn as_kilobits_per_second(&self) -> f64 {
+        self.as_bits_per_second() / 1e3
+    }
+
+    /// Convert this DataRate into a floating point value in megabits per second
+    pub fn as_megabits_per_second(&self) -> f64 {
+        self.as_bits_per_second() / 1e6
+    }
+
+    /// Convert this DataRate into a floating point value in gigabits per second
+    pub fn as_gigabits_per_second(&self) -> f64 {
+        self.as_bits_per_second() / 1e9
+    }
+}
+
+impl Measurement for DataRate {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.octets_per_second
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_octets_per_second(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "o/s"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("bit/s", 0.125),
+            ("kbit/s", 125.0),
+            ("Mbit/s", 125e3),
+            ("Gbit/s", 125e6),
+            ("Tbit/s", 125e9),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "DataRate"
+    }
+}
+
+implement_debug! { DataRate, octets_per_second }
+
+implement_measurement! { DataRate }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use data::Data;
+    use test_utils::assert_almost_eq;
+    use time;
+
+    #[test]
+    fn megabits_per_second() {
+        let i1 = DataRate::from_megabits_per_second(8.0);
+        let r1 = i1.as_megaoctets_per_second();
+        let i2 = DataRate::from_kilooctets_per_second(125.0);
+        let r2 = i2.as_megabits_per_second();
+        assert_almost_eq(r1, 1.0);
+        assert_almost_eq(r2, 1.0);
+    }
+
+    #[test]
+    fn data_over_time() {
+        let rate = DataRate::from_megaoctets_per_second(2.0);
+        let d: Data = rate * time::Duration::from_secs(30);
+        assert_almost_eq(d.as_megaoctets(), 60.0);
+        let r: DataRate = d / time::Duration::from_secs(30);
+        assert_almost_eq(r.as_megaoctets_per_second(), 2.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = DataRate::from_octets_per_second(2.0);
+        let b = DataRate::from_octets_per_second(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_octets_per_second(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = DataRate::from_octets_per_second(2.0);
+        let b = DataRate::from_octets_per_second(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_octets_per_second(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = DataRate::from_octets_per_second(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_octets_per_second(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = DataRate::from_octets_per_second(2.0);
+        let b = DataRate::from_octets_per_second(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_octets_per_second(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = DataRate::from_octets_per_second(2.0);
+        let b = DataRate::from_octets_per_second(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = DataRate::from_octets_per_second(2.0);
+        let b = DataRate::from_octets_per_second(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = DataRate::from_octets_per_second(2.0);
+        let b = DataRate::from_octets_per_second(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
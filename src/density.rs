@@ -1,12 +1,28 @@
 //! Types and constants for handling density.
 
 use super::measurement::*;
+use constants::MOLAR_GAS_CONSTANT;
 use mass::Mass;
+use pressure::Pressure;
+use temperature::Temperature;
 use volume::Volume;
 
 // Constants, metric
 /// Number of pound per cubic foot in 1 kilograms per cubic meter
 pub const LBCF_KGCM_FACTOR: f64 = 0.062427973725314;
+/// Density of (fresh) water in kilograms per cubic meter
+pub const WATER_KGCM: f64 = 1000.0;
+
+/// Units supported by [`Density`], as (symbol, long name, number of kilograms per cubic meter
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("kg/m\u{00B3}", "kilograms per cubic meter", 1.0),
+    (
+        "lb/ft\u{00B3}",
+        "pounds per cubic foot",
+        1.0 / LBCF_KGCM_FACTOR,
+    ),
+];
 
 /// The `Density` struct can be used to deal with Densities in a common way, to enable mass,
 /// volume and density calculations and unit conversions.
@@ -17,16 +33,14 @@ pub const LBCF_KGCM_FACTOR: f64 = 0.062427973725314;
 /// extern crate measurements;
 /// use measurements::{Density, Mass, Volume};
 ///
-/// fn main() {
-///    // Q: A 12 stone man hops into a brimming full bath, completely emersing himself.
-///    // How many gallons of water spill on the floor?
-///    // (Assume The human body is roughly about as dense as water - 1 gm/cm³)
-///    //
-///    let body_density: Density = Mass::from_grams(1.0) / Volume:: from_cubic_centimetres(1.0);
-///    let mans_weight = Mass::from_stones(12.0);
-///    let water_volume = mans_weight / body_density;
-///    println!("{} gallons of water spilled on the floor", water_volume.as_gallons());
-///}
+/// // Q: A 12 stone man hops into a brimming full bath, completely emersing himself.
+/// // How many gallons of water spill on the floor?
+/// // (Assume The human body is roughly about as dense as water - 1 gm/cm³)
+/// //
+/// let body_density: Density = Mass::from_grams(1.0) / Volume:: from_cubic_centimetres(1.0);
+/// let mans_weight = Mass::from_stones(12.0);
+/// let water_volume = mans_weight / body_density;
+/// println!("{} gallons of water spilled on the floor", water_volume.as_gallons());
 /// ```
 /// # Example2 - converting to ad-hoc units of density
 ///
@@ -34,17 +48,15 @@ pub const LBCF_KGCM_FACTOR: f64 = 0.062427973725314;
 /// extern crate measurements;
 /// use measurements::{Density, Mass, Volume};
 ///
-/// fn main() {
-///    // Q: what is 3 grams per litre in units of ounces per quart?
-///    //
-///    let density: Density = Mass::from_grams(3.0) / Volume:: from_litres(1.0);
-///    let ounces = (density * Volume::from_quarts(1.0)).as_ounces();
-///    println!("Answer is {} ounces per quart", ounces);
-///}
+/// // Q: what is 3 grams per litre in units of ounces per quart?
+/// //
+/// let density: Density = Mass::from_grams(3.0) / Volume:: from_litres(1.0);
+/// let ounces = (density * Volume::from_quarts(1.0)).as_ounces();
+/// println!("Answer is {} ounces per quart", ounces);
 /// ```
-
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Density {
     kilograms_per_cubic_meter: f64,
 }
@@ -71,6 +83,19 @@ impl Density {
     pub fn as_pounds_per_cubic_feet(&self) -> f64 {
         self.kilograms_per_cubic_meter * LBCF_KGCM_FACTOR
     }
+
+    /// Create a new Density of an ideal gas with the given molar mass, at the
+    /// given pressure and temperature: ρ = PM / (RT). For dry air, the molar
+    /// mass is about 0.02896 kg/mol.
+    pub fn of_ideal_gas(
+        pressure: Pressure,
+        temp: Temperature,
+        molar_mass_kg_per_mol: f64,
+    ) -> Density {
+        Density::from_kilograms_per_cubic_meter(
+            pressure.as_pascals() * molar_mass_kg_per_mol / (MOLAR_GAS_CONSTANT * temp.as_kelvin()),
+        )
+    }
 }
 
 // mass / volume = density
@@ -110,6 +135,8 @@ impl ::std::ops::Mul<Volume> for Density {
 }
 
 impl Measurement for Density {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.kilograms_per_cubic_meter
     }
@@ -121,8 +148,14 @@ impl Measurement for Density {
     fn get_base_units_name(&self) -> &'static str {
         "kg/m\u{00B3}"
     }
+
+    fn quantity_name() -> &'static str {
+        "Density"
+    }
 }
 
+implement_debug! { Density, kilograms_per_cubic_meter }
+
 implement_measurement! { Density }
 
 #[cfg(test)]
@@ -175,6 +208,16 @@ mod test {
         assert_almost_eq(a.as_pounds_per_cubic_feet(), b.as_pounds_per_cubic_feet());
     }
 
+    #[test]
+    fn of_ideal_gas() {
+        let air = Density::of_ideal_gas(
+            Pressure::from_atmospheres(1.0),
+            Temperature::from_celsius(15.0),
+            0.02896,
+        );
+        assert!((air.as_kilograms_per_cubic_meter() - 1.225).abs() < 0.001);
+    }
+
     // Traits
     #[test]
     fn add() {
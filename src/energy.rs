@@ -1,6 +1,28 @@
 //! Types and constants for handling energy.
 
 use super::measurement::*;
+use charge::Charge;
+use integrate::integrate_rate;
+use mass_flow_rate::MassFlowRate;
+#[cfg(feature = "from_str")]
+use parse::{parse_number, split_number_unit, unknown_unit, ParseMeasurementError};
+use power::Power;
+use specific_energy::SpecificEnergy;
+#[cfg(feature = "from_str")]
+use std::str::FromStr;
+use time;
+use voltage::Voltage;
+
+/// Units supported by [`Energy`], as (symbol, long name, number of joules
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("eV", "electronvolts", 1.0 / 6.241_509_479_607_718e18),
+    ("J", "joules", 1.0),
+    ("Wh", "watt hours", 3600.0),
+    ("kcal", "kilocalories", 4186.8),
+    ("BTU", "British thermal units", 1055.056),
+    ("kWh", "kilowatt hours", 3600.0 * 1000.0),
+];
 
 /// The `Energy` struct can be used to deal with energies in a common way.
 /// Common metric and imperial units are supported.
@@ -14,7 +36,8 @@ use super::measurement::*;
 /// println!("Some say a health adult male should consume {} per day", energy);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Energy {
     joules: f64,
 }
@@ -79,9 +102,67 @@ impl Energy {
     pub fn as_kilowatt_hours(&self) -> f64 {
         self.joules / (3600.0 * 1000.0)
     }
+
+    /// Integrate a series of power readings over time, where each reading
+    /// is the average power over the given period: E = Σ Pᵢ × Δtᵢ.
+    pub fn integrate(samples: &[(Power, time::Duration)]) -> Energy {
+        integrate_rate(samples)
+    }
+
+    /// Integrate a series of power readings over time using the trapezoidal
+    /// rule, where each reading is the power at the given time (e.g. since
+    /// the meter was started). The readings must be in order of time.
+    /// Fewer than two readings give no energy.
+    pub fn integrate_trapezoidal(samples: &[(Power, time::Duration)]) -> Energy {
+        let joules = samples
+            .windows(2)
+            .map(|pair| {
+                let (p0, t0) = pair[0];
+                let (p1, t1) = pair[1];
+                (p0.as_watts() + p1.as_watts()) / 2.0 * (t1.as_base_units() - t0.as_base_units())
+            })
+            .sum();
+        Self::from_joules(joules)
+    }
+
+    /// Create a new Energy released by burning a fuel flowing at the given
+    /// rate for the given time, given its heating value
+    pub fn from_mass_flow(
+        flow: MassFlowRate,
+        heating_value: SpecificEnergy,
+        over: time::Duration,
+    ) -> Self {
+        flow.power_output(heating_value) * over
+    }
+
+    /// Calculate how long this Energy lasts when drawn at the given constant
+    /// Power (E / P). Returns `None` if the draw is not positive, as the
+    /// runtime would be infinite.
+    pub fn runtime_at_power(&self, draw: Power) -> Option<time::Duration> {
+        if draw.as_watts() > 0.0 {
+            Some(time::Duration::from_base_units(
+                self.joules / draw.as_watts(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Calculate the Charge which holds this Energy at the given nominal
+    /// Voltage (E / V), e.g. to convert a battery's Wh rating to Ah. Returns
+    /// `None` if the voltage is zero.
+    pub fn charge_at_voltage(&self, v: Voltage) -> Option<Charge> {
+        if v.as_volts() != 0.0 {
+            Some(Charge::from_coulombs(self.joules / v.as_volts()))
+        } else {
+            None
+        }
+    }
 }
 
 impl Measurement for Energy {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.joules
     }
@@ -112,8 +193,46 @@ impl Measurement for Energy {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Energy"
+    }
+}
+
+#[cfg(feature = "from_str")]
+impl FromStr for Energy {
+    type Err = ParseMeasurementError;
+
+    /// Create a new Energy from a string
+    /// Plain numbers in string are considered to be Joules. Units are case
+    /// sensitive, to tell MJ and mJ apart.
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        if val.is_empty() {
+            return Ok(Energy::from_joules(0.0));
+        }
+
+        let (number, unit) = split_number_unit(val);
+        Ok(match unit.as_str() {
+            "" => Energy::from_joules(parse_number(number)?),
+            "\u{00B5}J" | "uJ" => Energy::from_joules(parse_number(number)? * 1e-6),
+            "mJ" => Energy::from_joules(parse_number(number)? * 1e-3),
+            "J" => Energy::from_joules(parse_number(number)?),
+            "kJ" => Energy::from_joules(parse_number(number)? * 1e3),
+            "MJ" => Energy::from_joules(parse_number(number)? * 1e6),
+            "GJ" => Energy::from_joules(parse_number(number)? * 1e9),
+            "Wh" => Energy::from_watt_hours(parse_number(number)?),
+            "kWh" => Energy::from_kilowatt_hours(parse_number(number)?),
+            "cal" => Energy::from_kcalories(parse_number(number)? / 1000.0),
+            "kcal" | "Cal" => Energy::from_kcalories(parse_number(number)?),
+            "BTU" | "Btu" => Energy::from_btu(parse_number(number)?),
+            "eV" => Energy::from_e_v(parse_number(number)?),
+            _ => return Err(unknown_unit(&unit)),
+        })
+    }
 }
 
+implement_debug! { Energy, joules }
+
 implement_measurement! { Energy }
 
 #[cfg(test)]
@@ -166,7 +285,7 @@ mod test {
         let r2 = i2.as_watt_hours();
 
         assert_almost_eq(r1, 360000.0);
-        assert_almost_eq(r2, 0.02777777777777777777777777777778);
+        assert_almost_eq(r2, 0.027777777777777776);
     }
 
     #[test]
@@ -178,7 +297,141 @@ mod test {
         let r2 = i2.as_kilowatt_hours();
 
         assert_almost_eq(r1, 360000000.0);
-        assert_almost_eq(r2, 2.777777777777777777777777777778e-5);
+        assert_almost_eq(r2, 2.777777777777778e-5);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn megajoules_str() {
+        let t = Energy::from_str("3.6 MJ");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_joules();
+        assert_almost_eq(o, 3.6e6);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn millijoules_str() {
+        let t = Energy::from_str("3.6mJ");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_joules();
+        assert_almost_eq(o, 3.6e-3);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn kilowatt_hours_str() {
+        let t = Energy::from_str("1 kWh");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_joules();
+        assert_almost_eq(o, 3.6e6);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn calories_str() {
+        let t = Energy::from_str("1000 cal");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_kcalories();
+        assert_almost_eq(o, 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn number_str() {
+        let t = Energy::from_str("100.5");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_joules();
+        assert_almost_eq(o, 100.5);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn invalid_str() {
+        let t = Energy::from_str("abcd");
+        assert!(t.is_err());
+    }
+
+    #[test]
+    fn integrate_constant() {
+        let second = time::Duration::from_secs(1);
+        let samples = [(Power::from_watts(100.0), second); 60];
+        let e = Energy::integrate(&samples);
+        assert_almost_eq(e.as_joules(), 6000.0);
+        assert_eq!(Energy::integrate(&[]).as_joules(), 0.0);
+    }
+
+    #[test]
+    fn integrate_steps() {
+        let samples = [
+            (Power::from_kilowatts(1.0), time::Duration::from_secs(1800)),
+            (Power::from_kilowatts(2.0), time::Duration::from_secs(3600)),
+        ];
+        let e = Energy::integrate(&samples);
+        assert_almost_eq(e.as_kilowatt_hours(), 2.5);
+    }
+
+    #[test]
+    fn integrate_trapezoidal_ramp() {
+        // Ramp from 0 W to 100 W over 10 s
+        let mut samples = [(Power::from_watts(0.0), time::Duration::from_secs(0)); 11];
+        for (i, sample) in samples.iter_mut().enumerate() {
+            *sample = (
+                Power::from_watts(i as f64 * 10.0),
+                time::Duration::from_secs(i as u64),
+            );
+        }
+        let e = Energy::integrate_trapezoidal(&samples);
+        assert_almost_eq(e.as_joules(), 500.0);
+    }
+
+    #[test]
+    fn integrate_trapezoidal_constant() {
+        let samples = [
+            (Power::from_watts(100.0), time::Duration::from_secs(0)),
+            (Power::from_watts(100.0), time::Duration::from_millis(2500)),
+        ];
+        let e = Energy::integrate_trapezoidal(&samples);
+        assert_almost_eq(e.as_joules(), 250.0);
+        assert_eq!(
+            Energy::integrate_trapezoidal(&samples[..1]).as_joules(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn runtime_at_power() {
+        let battery = Energy::from_watt_hours(24.0);
+        let runtime = battery.runtime_at_power(Power::from_watts(6.0));
+        assert_eq!(runtime, Some(time::Duration::from_secs(4 * 3600)));
+        assert_eq!(battery.runtime_at_power(Power::from_watts(0.0)), None);
+    }
+
+    #[test]
+    fn charge_at_voltage() {
+        let battery = Energy::from_watt_hours(120.0);
+        let charge = battery
+            .charge_at_voltage(Voltage::from_volts(12.0))
+            .unwrap();
+        assert_almost_eq(charge.as_ampere_hours(), 10.0);
+        assert!(battery
+            .charge_at_voltage(Voltage::from_volts(0.0))
+            .is_none());
+    }
+
+    #[test]
+    fn from_mass_flow() {
+        let e = Energy::from_mass_flow(
+            MassFlowRate::from_grams_per_second(2.0),
+            SpecificEnergy::from_megajoules_per_kilogram(42.6),
+            time::Duration::from_secs(3600),
+        );
+        assert_almost_eq(e.as_kilowatt_hours(), 85.2);
     }
 
     // Traits
@@ -0,0 +1,195 @@
+//! Types and constants for handling light exposure.
+
+use super::measurement::*;
+
+/// Units supported by [`Exposure`], as (symbol, long name, number of
+/// lux seconds in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("lx\u{00B7}s", "lux seconds", 1.0),
+    ("lx\u{00B7}h", "lux hours", 3600.0),
+];
+
+/// The `Exposure` struct can be used to deal with light exposure (illuminance
+/// over a period of time) in a common way, such as when exposing film or a
+/// sensor, or in limiting the light falling on artworks.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Exposure, Illuminance};
+/// use std::time::Duration;
+///
+/// let lamp = Illuminance::from_lux(50.0);
+/// let exposure: Exposure = lamp * Duration::from_secs(8 * 3600);
+/// println!("The painting received {} per day.", exposure);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Exposure {
+    lux_seconds: f64,
+}
+
+impl Exposure {
+    /// Create a new Exposure from a floating point value in lux seconds
+    pub fn from_lux_seconds(lux_seconds: f64) -> Self {
+        Exposure { lux_seconds }
+    }
+
+    /// Create a new Exposure from a floating point value in lux hours
+    pub fn from_lux_hours(lux_hours: f64) -> Self {
+        Self::from_lux_seconds(lux_hours * 3600.0)
+    }
+
+    /// Convert this Exposure to a floating point value in lux seconds
+    pub fn as_lux_seconds(&self) -> f64 {
+        self.lux_seconds
+    }
+
+    /// Convert this Exposure to a floating point value in lux hours
+    pub fn as_lux_hours(&self) -> f64 {
+        self.lux_seconds / 3600.0
+    }
+}
+
+impl Measurement for Exposure {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.lux_seconds
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_lux_seconds(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "lx\u{00B7}s"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [
+            ("mlx\u{00B7}s", 1e-3),
+            ("lx\u{00B7}s", 1e0),
+            ("klx\u{00B7}s", 1e3),
+            ("Mlx\u{00B7}s", 1e6),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "Exposure"
+    }
+}
+
+implement_debug! { Exposure, lux_seconds }
+
+implement_measurement! { Exposure }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use illuminance::Illuminance;
+    use std::time::Duration;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn lux_hours() {
+        let i1 = Exposure::from_lux_hours(2.0);
+        let r1 = i1.as_lux_seconds();
+        let i2 = Exposure::from_lux_seconds(1800.0);
+        let r2 = i2.as_lux_hours();
+        assert_almost_eq(r1, 7200.0);
+        assert_almost_eq(r2, 0.5);
+    }
+
+    #[test]
+    fn illuminance_times_duration() {
+        let i = Illuminance::from_lux(1000.0);
+        let t = Duration::from_secs(2);
+        let a: Exposure = i * t;
+        let b: Exposure = t * i;
+        assert_almost_eq(a.as_lux_seconds(), 2000.0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn exposure_over_duration() {
+        let e = Exposure::from_lux_seconds(2000.0);
+        let i: Illuminance = e / Duration::from_secs(4);
+        assert_almost_eq(i.as_lux(), 500.0);
+        let t: Duration = e / Illuminance::from_lux(1000.0);
+        assert_eq!(t, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn appropriate_units() {
+        let e = Exposure::from_lux_seconds(2000.0);
+        let (unit, value) = e.get_appropriate_units();
+        assert_eq!(unit, "klx\u{00B7}s");
+        assert_almost_eq(value, 2.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Exposure::from_lux_seconds(2.0);
+        let b = Exposure::from_lux_seconds(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_lux_seconds(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = Exposure::from_lux_seconds(2.0);
+        let b = Exposure::from_lux_seconds(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_lux_seconds(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = Exposure::from_lux_seconds(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_lux_seconds(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = Exposure::from_lux_seconds(2.0);
+        let b = Exposure::from_lux_seconds(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_lux_seconds(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = Exposure::from_lux_seconds(2.0);
+        let b = Exposure::from_lux_seconds(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = Exposure::from_lux_seconds(2.0);
+        let b = Exposure::from_lux_seconds(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Exposure::from_lux_seconds(2.0);
+        let b = Exposure::from_lux_seconds(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
@@ -1,6 +1,11 @@
 //! Types and constants for handling force.
 
 use super::measurement::*;
+use area::Area;
+use energy::Energy;
+use length::Length;
+use pressure::Pressure;
+use torque::Torque;
 
 /// Number of POUNDS force in a Newton
 pub const POUNDS_PER_NEWTON: f64 = 0.224809;
@@ -12,6 +17,18 @@ pub const KILOPONDS_PER_NEWTON: f64 = 1.0 / 9.80665;
 /// Number of DYNES in a Newton
 pub const DYNES_PER_NEWTON: f64 = 1e5;
 
+/// Units supported by [`Force`], as (symbol, long name, number of newtons
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("dyn", "dynes", 1.0 / DYNES_PER_NEWTON),
+    ("\u{00B5}N", "micronewtons", 1e-6),
+    ("mN", "millinewtons", 1e-3),
+    ("N", "newtons", 1.0),
+    ("pdl", "poundals", 1.0 / POUNDALS_PER_NEWTON),
+    ("lbf", "pounds", 1.0 / POUNDS_PER_NEWTON),
+    ("kp", "kiloponds", 1.0 / KILOPONDS_PER_NEWTON),
+];
+
 /// The `Force` struct can be used to deal with force in a common way.
 ///
 /// #Example
@@ -29,7 +46,8 @@ pub const DYNES_PER_NEWTON: f64 = 1e5;
 ///     force);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Force {
     newtons: f64,
 }
@@ -104,9 +122,31 @@ impl Force {
     pub fn as_dynes(&self) -> f64 {
         self.newtons * DYNES_PER_NEWTON
     }
+
+    /// Calculate the Pressure from this Force spread evenly over the given
+    /// Area (P = F / A). This is the same as `force / area`.
+    pub fn pressure_over(&self, area: Area) -> Pressure {
+        *self / area
+    }
+
+    /// Calculate the work done (Energy) by this Force moving something the
+    /// given distance along its direction (W = F × d). Multiplying a Force by
+    /// a Length gives a `TorqueEnergy`; this gives an Energy directly.
+    pub fn work_over(&self, distance: Length) -> Energy {
+        (*self * distance).into()
+    }
+
+    /// Calculate the Torque from this Force applied at right angles to a
+    /// lever arm of the given length (τ = F × r). Multiplying a Force by a
+    /// Length gives a `TorqueEnergy`; this gives a Torque directly.
+    pub fn torque_at(&self, lever_arm: Length) -> Torque {
+        (*self * lever_arm).into()
+    }
 }
 
 impl Measurement for Force {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.newtons
     }
@@ -133,8 +173,14 @@ impl Measurement for Force {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Force"
+    }
 }
 
+implement_debug! { Force, newtons }
+
 implement_measurement! { Force }
 
 #[cfg(test)]
@@ -222,6 +268,37 @@ mod test {
         assert_almost_eq(r2, 0.001);
     }
 
+    #[test]
+    fn component_ratio() {
+        let fx = Force::from_newtons(3.0);
+        let fy = Force::from_newtons(4.0);
+        let total = Force::from_newtons(5.0);
+        assert_almost_eq(fx.component_ratio(&total), 0.6);
+        assert_almost_eq(fy.component_ratio(&total), 0.8);
+        assert_eq!(fx.component_ratio(&Force::from_newtons(0.0)), 0.0);
+    }
+
+    #[test]
+    fn pressure_over() {
+        let f = Force::from_newtons(200_000.0);
+        let p = f.pressure_over(Area::from_square_meters(2.0));
+        assert_almost_eq(p.as_kilopascals(), 100.0);
+    }
+
+    #[test]
+    fn work_over() {
+        let f = Force::from_newtons(50.0);
+        let e = f.work_over(Length::from_meters(3.0));
+        assert_almost_eq(e.as_joules(), 150.0);
+    }
+
+    #[test]
+    fn torque_at() {
+        let f = Force::from_newtons(50.0);
+        let t = f.torque_at(Length::from_centimeters(40.0));
+        assert_almost_eq(t.as_newton_metres(), 20.0);
+    }
+
     #[test]
     fn add() {
         let a = Force::from_newtons(2.0);
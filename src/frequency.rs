@@ -1,6 +1,8 @@
 //! Types and constants for handling frequencies.
 
 use super::measurement::*;
+use length::Length;
+use speed::Speed;
 use time;
 
 /// Number of nanohertz in a Hz
@@ -18,6 +20,19 @@ pub const HERTZ_GIGAHERTZ_FACTOR: f64 = 1e-9;
 /// Number of THz in a Hz
 pub const HERTZ_TERAHERTZ_FACTOR: f64 = 1e-12;
 
+/// Units supported by [`Frequency`], as (symbol, long name, number of hertz
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("nHz", "nanohertz", 1.0 / HERTZ_NANOHERTZ_FACTOR),
+    ("\u{00B5}Hz", "microhertz", 1.0 / HERTZ_MICROHERTZ_FACTOR),
+    ("mHz", "millihertz", 1.0 / HERTZ_MILLIHERTZ_FACTOR),
+    ("Hz", "hertz", 1.0),
+    ("kHz", "kilohertz", 1.0 / HERTZ_KILOHERTZ_FACTOR),
+    ("MHz", "megahertz", 1.0 / HERTZ_MEGAHERTZ_FACTOR),
+    ("GHz", "gigahertz", 1.0 / HERTZ_GIGAHERTZ_FACTOR),
+    ("THz", "terahertz", 1.0 / HERTZ_TERAHERTZ_FACTOR),
+];
+
 /// The Frequency struct can be used to deal with frequencies in a common way.
 /// Common SI prefixes are supported.
 ///
@@ -30,7 +45,8 @@ pub const HERTZ_TERAHERTZ_FACTOR: f64 = 1e-12;
 /// println!("Tune to {}.", radio_station);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Frequency {
     hertz: f64,
 }
@@ -84,6 +100,20 @@ impl Frequency {
         Self::from_hertz(1.0 / period.as_base_units())
     }
 
+    /// Create a new Frequency from a MIDI note number, in twelve-tone equal
+    /// temperament tuned to A4 (note 69) at 440 Hz.
+    #[cfg(feature = "std")]
+    pub fn from_midi_note(note: u8) -> Self {
+        Self::from_hertz(440.0 * 2f64.powf((f64::from(note) - 69.0) / 12.0))
+    }
+
+    /// Create a new Frequency from a MIDI note number, in twelve-tone equal
+    /// temperament tuned to A4 (note 69) at 440 Hz.
+    #[cfg(not(feature = "std"))]
+    pub fn from_midi_note(note: u8) -> Self {
+        Self::from_hertz(440.0 * libm::pow(2.0, (f64::from(note) - 69.0) / 12.0))
+    }
+
     /// Convert this Frequency to a floating point value in Nanohertz
     pub fn as_nanohertz(&self) -> f64 {
         self.hertz * HERTZ_NANOHERTZ_FACTOR
@@ -128,9 +158,95 @@ impl Frequency {
     pub fn as_period(&self) -> time::Duration {
         time::Duration::from_base_units(1.0 / self.hertz)
     }
+
+    /// Find the nearest MIDI note to this Frequency (with A4, note 69, at
+    /// 440 Hz), and how far this Frequency is from that note in cents
+    /// (hundredths of a semitone, from -50 to +50). Frequencies outside the
+    /// range of MIDI notes give note 0 or 127, with a larger offset.
+    #[cfg(feature = "std")]
+    pub fn as_nearest_midi_note(&self) -> (u8, f64) {
+        let note = 69.0 + 12.0 * (self.hertz / 440.0).log2();
+        let nearest = note.round().clamp(0.0, 127.0);
+        (nearest as u8, (note - nearest) * 100.0)
+    }
+
+    /// Find the nearest MIDI note to this Frequency (with A4, note 69, at
+    /// 440 Hz), and how far this Frequency is from that note in cents
+    /// (hundredths of a semitone, from -50 to +50). Frequencies outside the
+    /// range of MIDI notes give note 0 or 127, with a larger offset.
+    #[cfg(not(feature = "std"))]
+    pub fn as_nearest_midi_note(&self) -> (u8, f64) {
+        let note = 69.0 + 12.0 * libm::log2(self.hertz / 440.0);
+        let nearest = libm::fmin(libm::fmax(libm::round(note), 0.0), 127.0);
+        (nearest as u8, (note - nearest) * 100.0)
+    }
+
+    /// Get the first `count` harmonics of this (fundamental) Frequency, i.e.
+    /// f, 2f, 3f, etc. The first harmonic is the fundamental itself.
+    pub fn harmonics(&self, count: usize) -> impl Iterator<Item = Frequency> {
+        let hertz = self.hertz;
+        (1..=count).map(move |n| Frequency::from_hertz(hertz * n as f64))
+    }
+
+    /// Treating this Frequency as a rate of revolution, calculate the ground
+    /// speed of a wheel with the given circumference, assuming it rolls
+    /// without slipping (f × C)
+    pub fn distance_per_revolution(&self, wheel_circumference: Length) -> Speed {
+        Speed::from_meters_per_second(self.hertz * wheel_circumference.as_meters())
+    }
+
+    /// Calculate the Frequency heard by an observer when a source emits this
+    /// Frequency, using the classical Doppler formula f' = f × (c + vo) / (c - vs),
+    /// where c is the speed of the wave in the medium (e.g. about 343 m/s for
+    /// sound in air).
+    ///
+    /// Both speeds are positive when moving towards the other party, and
+    /// negative when moving away, so an approaching source raises the pitch
+    /// and a receding one lowers it.
+    pub fn doppler_shifted(
+        &self,
+        source_speed: Speed,
+        observer_speed: Speed,
+        wave_speed: Speed,
+    ) -> Frequency {
+        let c = wave_speed.as_meters_per_second();
+        Frequency::from_hertz(
+            self.hertz * (c + observer_speed.as_meters_per_second())
+                / (c - source_speed.as_meters_per_second()),
+        )
+    }
+
+    /// Calculate the beat frequency heard when this Frequency and another are
+    /// played together (|f1 - f2|)
+    #[cfg(feature = "std")]
+    pub fn beat_with(&self, other: Frequency) -> Frequency {
+        Frequency::from_hertz((self.hertz - other.hertz).abs())
+    }
+
+    /// Calculate the beat frequency heard when this Frequency and another are
+    /// played together (|f1 - f2|)
+    #[cfg(not(feature = "std"))]
+    pub fn beat_with(&self, other: Frequency) -> Frequency {
+        Frequency::from_hertz(libm::fabs(self.hertz - other.hertz))
+    }
+
+    /// Treating this Frequency as a sample rate, get the Nyquist frequency,
+    /// the highest frequency that can be sampled without aliasing (f / 2)
+    pub fn nyquist(&self) -> Frequency {
+        Frequency::from_hertz(self.hertz / 2.0)
+    }
+
+    /// Treating this Frequency as a sample rate, calculate the number of
+    /// samples taken over the given Duration (f × t). This is not rounded, so
+    /// a partial sample gives a fractional count.
+    pub fn samples_over(&self, duration: time::Duration) -> f64 {
+        self.hertz * duration.as_base_units()
+    }
 }
 
 impl Measurement for Frequency {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.hertz
     }
@@ -157,8 +273,14 @@ impl Measurement for Frequency {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Frequency"
+    }
 }
 
+implement_debug! { Frequency, hertz }
+
 implement_measurement! { Frequency }
 
 #[cfg(test)]
@@ -254,6 +376,85 @@ mod test {
         assert_almost_eq(r2, 1e-2);
     }
 
+    #[test]
+    fn harmonics() {
+        let a4 = Frequency::from_hertz(440.0);
+        let mut h = a4.harmonics(3);
+        assert_almost_eq(h.next().unwrap().as_hertz(), 440.0);
+        assert_almost_eq(h.next().unwrap().as_hertz(), 880.0);
+        assert_almost_eq(h.next().unwrap().as_hertz(), 1320.0);
+        assert!(h.next().is_none());
+        assert!(a4.harmonics(0).next().is_none());
+    }
+
+    #[test]
+    fn from_midi_note() {
+        assert_almost_eq(Frequency::from_midi_note(69).as_hertz(), 440.0);
+        assert_almost_eq(Frequency::from_midi_note(57).as_hertz(), 220.0);
+        assert_almost_eq(Frequency::from_midi_note(60).as_hertz(), 261.625565);
+    }
+
+    #[test]
+    fn as_nearest_midi_note() {
+        let (note, cents) = Frequency::from_hertz(440.0).as_nearest_midi_note();
+        assert_eq!(note, 69);
+        assert_eq!(cents, 0.0);
+        let (note, cents) = Frequency::from_hertz(445.0).as_nearest_midi_note();
+        assert_eq!(note, 69);
+        assert_almost_eq(cents, 19.562175);
+        let (note, cents) = Frequency::from_hertz(255.0).as_nearest_midi_note();
+        assert_eq!(note, 60);
+        assert_almost_eq(cents, -44.407532);
+        let (note, _) = Frequency::from_midi_note(57).as_nearest_midi_note();
+        assert_eq!(note, 57);
+    }
+
+    #[test]
+    fn as_nearest_midi_note_out_of_range() {
+        let (note, cents) = Frequency::from_hertz(1.0).as_nearest_midi_note();
+        assert_eq!(note, 0);
+        assert!(cents < -50.0);
+        let (note, _) = Frequency::from_hertz(100_000.0).as_nearest_midi_note();
+        assert_eq!(note, 127);
+    }
+
+    #[test]
+    fn distance_per_revolution() {
+        let wheel = Length::circumference_from_radius(Length::from_meters(0.3));
+        let f = Frequency::from_hertz(10.0 / (2.0 * ::PI));
+        let v = f.distance_per_revolution(wheel);
+        assert_almost_eq(v.as_meters_per_second(), 3.0);
+    }
+
+    #[test]
+    fn beat_with() {
+        let a = Frequency::from_hertz(440.0);
+        let b = Frequency::from_hertz(443.0);
+        assert_almost_eq(a.beat_with(b).as_hertz(), 3.0);
+        assert_almost_eq(b.beat_with(a).as_hertz(), 3.0);
+    }
+
+    #[test]
+    fn doppler_shifted() {
+        let siren = Frequency::from_hertz(700.0);
+        let air = Speed::from_meters_per_second(343.0);
+        let still = Speed::from_meters_per_second(0.0);
+        let approaching = siren.doppler_shifted(Speed::from_meters_per_second(30.0), still, air);
+        assert_almost_eq(approaching.as_hertz(), 767.092652);
+        let receding = siren.doppler_shifted(Speed::from_meters_per_second(-30.0), still, air);
+        assert_almost_eq(receding.as_hertz(), 643.699732);
+        let moving_observer =
+            siren.doppler_shifted(still, Speed::from_meters_per_second(30.0), air);
+        assert_almost_eq(moving_observer.as_hertz(), 761.224490);
+    }
+
+    #[test]
+    fn sample_rate() {
+        let rate = Frequency::from_kilohertz(48.0);
+        assert_almost_eq(rate.nyquist().as_kilohertz(), 24.0);
+        assert_almost_eq(rate.samples_over(time::Duration::from_millis(10)), 480.0);
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -0,0 +1,206 @@
+//! Types and constants for handling fuel economy (or fuel consumption).
+
+use super::measurement::*;
+
+/// Number of litres in a US gallon
+const LITER_US_GALLON_FACTOR: f64 = 3.785411784;
+/// Number of litres in an imperial gallon
+const LITER_IMPERIAL_GALLON_FACTOR: f64 = 4.54609;
+/// Number of kilometres in a mile
+const KILOMETER_MILE_FACTOR: f64 = 1.609344;
+
+/// Units supported by [`FuelEconomy`], as (symbol, long name, number of litres per kilometre
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("l/100km", "litres per 100 kilometres", 1e-2),
+    ("l/km", "litres per kilometre", 1.0),
+];
+
+/// The `FuelEconomy` struct can be used to deal with fuel economy in a common
+/// way. Metric consumption (litres per 100 km) and US and imperial miles per
+/// gallon are supported.
+///
+/// Internally, fuel economy is stored as a consumption (litres used per
+/// kilometre travelled). Miles per gallon is the reciprocal of that: it is a
+/// distance travelled per volume of fuel used. As such, a larger MPG figure
+/// is a smaller consumption, and converting between the two is a division
+/// rather than a multiplication:
+///
+/// ```text
+/// l/100km = 100 * (litres per gallon) / ((km per mile) * mpg)
+/// ```
+///
+/// A fuel economy of zero miles per gallon is an infinite consumption, and
+/// vice versa.
+///
+/// # Example
+///
+/// ```
+/// use measurements::FuelEconomy;
+///
+/// let hatchback = FuelEconomy::from_liters_per_100km(5.0);
+/// println!("That's {:.1} mpg in the US.", hatchback.as_mpg_us());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct FuelEconomy {
+    liters_per_kilometer: f64,
+}
+
+impl FuelEconomy {
+    /// Create a new FuelEconomy from a floating point value in litres per kilometre
+    pub fn from_liters_per_kilometer(liters_per_kilometer: f64) -> Self {
+        FuelEconomy {
+            liters_per_kilometer,
+        }
+    }
+
+    /// Create a new FuelEconomy from a floating point value in litres per 100 kilometres
+    pub fn from_liters_per_100km(liters_per_100km: f64) -> Self {
+        Self::from_liters_per_kilometer(liters_per_100km / 100.0)
+    }
+
+    /// Create a new FuelEconomy from a floating point value in litres per 100 kilometres
+    pub fn from_litres_per_100km(litres_per_100km: f64) -> Self {
+        Self::from_liters_per_100km(litres_per_100km)
+    }
+
+    /// Create a new FuelEconomy from a floating point value in kilometres per litre
+    pub fn from_kilometers_per_liter(kilometers_per_liter: f64) -> Self {
+        Self::from_liters_per_kilometer(1.0 / kilometers_per_liter)
+    }
+
+    /// Create a new FuelEconomy from a floating point value in miles per US gallon
+    pub fn from_mpg_us(mpg: f64) -> Self {
+        Self::from_liters_per_kilometer(LITER_US_GALLON_FACTOR / (KILOMETER_MILE_FACTOR * mpg))
+    }
+
+    /// Create a new FuelEconomy from a floating point value in miles per imperial gallon
+    pub fn from_mpg_imperial(mpg: f64) -> Self {
+        Self::from_liters_per_kilometer(
+            LITER_IMPERIAL_GALLON_FACTOR / (KILOMETER_MILE_FACTOR * mpg),
+        )
+    }
+
+    /// Convert this FuelEconomy to a floating point value in litres per kilometre
+    pub fn as_liters_per_kilometer(&self) -> f64 {
+        self.liters_per_kilometer
+    }
+
+    /// Convert this FuelEconomy to a floating point value in litres per 100 kilometres
+    pub fn as_liters_per_100km(&self) -> f64 {
+        self.liters_per_kilometer * 100.0
+    }
+
+    /// Convert this FuelEconomy to a floating point value in litres per 100 kilometres
+    pub fn as_litres_per_100km(&self) -> f64 {
+        self.as_liters_per_100km()
+    }
+
+    /// Convert this FuelEconomy to a floating point value in kilometres per litre
+    pub fn as_kilometers_per_liter(&self) -> f64 {
+        1.0 / self.liters_per_kilometer
+    }
+
+    /// Convert this FuelEconomy to a floating point value in miles per US gallon
+    pub fn as_mpg_us(&self) -> f64 {
+        LITER_US_GALLON_FACTOR / (KILOMETER_MILE_FACTOR * self.liters_per_kilometer)
+    }
+
+    /// Convert this FuelEconomy to a floating point value in miles per imperial gallon
+    pub fn as_mpg_imperial(&self) -> f64 {
+        LITER_IMPERIAL_GALLON_FACTOR / (KILOMETER_MILE_FACTOR * self.liters_per_kilometer)
+    }
+}
+
+impl Measurement for FuelEconomy {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.liters_per_kilometer
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_liters_per_kilometer(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "l/km"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [("l/100km", 1e-2), ("l/km", 1e0)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "FuelEconomy"
+    }
+}
+
+implement_debug! { FuelEconomy, liters_per_kilometer }
+
+implement_measurement! { FuelEconomy }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn liters_per_100km() {
+        let i1 = FuelEconomy::from_liters_per_100km(5.0);
+        let r1 = i1.as_liters_per_kilometer();
+        let i2 = FuelEconomy::from_liters_per_kilometer(0.08);
+        let r2 = i2.as_liters_per_100km();
+        assert_almost_eq(r1, 0.05);
+        assert_almost_eq(r2, 8.0);
+    }
+
+    #[test]
+    fn kilometers_per_liter() {
+        let i1 = FuelEconomy::from_liters_per_100km(5.0);
+        let r1 = i1.as_kilometers_per_liter();
+        let i2 = FuelEconomy::from_kilometers_per_liter(10.0);
+        let r2 = i2.as_liters_per_100km();
+        assert_almost_eq(r1, 20.0);
+        assert_almost_eq(r2, 10.0);
+    }
+
+    #[test]
+    fn mpg_us() {
+        let i1 = FuelEconomy::from_liters_per_100km(5.0);
+        let r1 = i1.as_mpg_us();
+        let i2 = FuelEconomy::from_mpg_us(30.0);
+        let r2 = i2.as_liters_per_100km();
+        assert_almost_eq(r1, 47.0429);
+        assert_almost_eq(r2, 7.84048);
+    }
+
+    #[test]
+    fn mpg_imperial() {
+        let i1 = FuelEconomy::from_liters_per_100km(5.0);
+        let r1 = i1.as_mpg_imperial();
+        let i2 = FuelEconomy::from_mpg_imperial(30.0);
+        let r2 = i2.as_liters_per_100km();
+        assert_almost_eq(r1, 56.4962);
+        assert_almost_eq(r2, 9.41609);
+    }
+
+    #[test]
+    fn mpg_round_trip() {
+        let i1 = FuelEconomy::from_mpg_us(42.0);
+        let r1 = FuelEconomy::from_mpg_imperial(i1.as_mpg_imperial());
+        assert_almost_eq(r1.as_mpg_us(), 42.0);
+    }
+
+    #[test]
+    fn appropriate_units() {
+        let i1 = FuelEconomy::from_liters_per_100km(5.0);
+        let (unit, value) = i1.get_appropriate_units();
+        assert_eq!(unit, "l/100km");
+        assert_almost_eq(value, 5.0);
+    }
+}
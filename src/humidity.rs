@@ -5,6 +5,9 @@ use density::Density;
 use pressure::Pressure;
 use temperature::Temperature;
 
+/// Specific gas constant for water vapor, in J/(kg·K)
+pub const WATER_VAPOR_GAS_CONSTANT: f64 = 461.5;
+
 /// The `Humidity` struct can be used to deal with relative humidity
 /// in air in a common way. Relative humidity is an important metric used
 /// in weather forecasts.
@@ -37,7 +40,8 @@ use temperature::Temperature;
 ///
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Humidity {
     relative_humidity: f64, // expressed as a percentage
 }
@@ -114,11 +118,13 @@ impl Humidity {
     }
 
     /// Calculates the absolute humidity (i.e. the density of water vapor in the air (kg/m3)), using
-    /// the Ideal Gas Law equation.
+    /// the Ideal Gas Law equation. This only needs `as_vapor_pressure`, so it is available with
+    /// and without the `std` feature.
     pub fn as_absolute_humidity(&self, temp: Temperature) -> Density {
         // use the Ideal Gas Law equation (Density = Pressure / (Temperature * [gas constant
-        // for water vapor= 461.5 (J/kg*Kelvin)]))
-        let density = self.as_vapor_pressure(temp).as_pascals() / (temp.as_kelvin() * 461.5);
+        // for water vapor]))
+        let density = self.as_vapor_pressure(temp).as_pascals()
+            / (temp.as_kelvin() * WATER_VAPOR_GAS_CONSTANT);
         Density::from_kilograms_per_cubic_meter(density)
     }
 
@@ -161,6 +167,10 @@ impl Measurement for Humidity {
     fn get_base_units_name(&self) -> &'static str {
         "%"
     }
+
+    fn quantity_name() -> &'static str {
+        "Humidity"
+    }
 }
 
 impl ::std::cmp::Eq for Humidity {}
@@ -176,6 +186,7 @@ impl ::std::cmp::PartialOrd for Humidity {
     }
 }
 
+implement_debug!(Humidity, relative_humidity);
 implement_display!(Humidity);
 
 #[cfg(test)]
@@ -242,6 +253,17 @@ mod test {
         let density = humidity.as_absolute_humidity(temp);
         assert_almost_eq(density.as_kilograms_per_cubic_meter(), 0.0138166);
     }
+
+    #[test]
+    fn absolute_humidity_saturated() {
+        // Runs against the libm vapor pressure when built without `std`
+        let humidity = Humidity::from_percent(100.0);
+        let temp = Temperature::from_celsius(20.0);
+        let density = humidity.as_absolute_humidity(temp);
+        assert_almost_eq(density.as_kilograms_per_cubic_meter() * 1000.0, 17.284071);
+        let dry = Humidity::from_percent(0.0).as_absolute_humidity(temp);
+        assert_eq!(dry.as_kilograms_per_cubic_meter(), 0.0);
+    }
     #[test]
     // round-trip test
     fn from_dewpoint2() {
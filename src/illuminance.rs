@@ -0,0 +1,190 @@
+//! Types and constants for handling illuminance.
+
+use super::measurement::*;
+
+/// Number of lux in a foot-candle (one lumen per square foot)
+pub const LUX_FOOTCANDLE_FACTOR: f64 = 10.763_910_416_709_722;
+
+/// Units supported by [`Illuminance`], as (symbol, long name, number of lux
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("lx", "lux", 1.0),
+    ("klx", "kilolux", 1e3),
+    ("fc", "foot-candles", LUX_FOOTCANDLE_FACTOR),
+];
+
+/// The `Illuminance` struct can be used to deal with illuminance (the amount
+/// of light falling on a surface) in a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::Illuminance;
+///
+/// let office = Illuminance::from_lux(500.0);
+/// println!("An office should be lit to {:.0} foot-candles.", office.as_footcandles());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Illuminance {
+    lux: f64,
+}
+
+impl Illuminance {
+    /// Create a new Illuminance from a floating point value in lux
+    pub fn from_lux(lux: f64) -> Self {
+        Illuminance { lux }
+    }
+
+    /// Create a new Illuminance from a floating point value in kilolux
+    pub fn from_kilolux(kilolux: f64) -> Self {
+        Self::from_lux(kilolux * 1000.0)
+    }
+
+    /// Create a new Illuminance from a floating point value in foot-candles
+    pub fn from_footcandles(footcandles: f64) -> Self {
+        Self::from_lux(footcandles * LUX_FOOTCANDLE_FACTOR)
+    }
+
+    /// Convert this Illuminance to a floating point value in lux
+    pub fn as_lux(&self) -> f64 {
+        self.lux
+    }
+
+    /// Convert this Illuminance to a floating point value in kilolux
+    pub fn as_kilolux(&self) -> f64 {
+        self.lux / 1000.0
+    }
+
+    /// Convert this Illuminance to a floating point value in foot-candles
+    pub fn as_footcandles(&self) -> f64 {
+        self.lux / LUX_FOOTCANDLE_FACTOR
+    }
+}
+
+impl Measurement for Illuminance {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.lux
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_lux(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "lx"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [("mlx", 1e-3), ("lx", 1e0), ("klx", 1e3)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "Illuminance"
+    }
+}
+
+implement_debug! { Illuminance, lux }
+
+implement_measurement! { Illuminance }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn kilolux() {
+        let i1 = Illuminance::from_kilolux(100.0);
+        let r1 = i1.as_lux();
+        let i2 = Illuminance::from_lux(500.0);
+        let r2 = i2.as_kilolux();
+        assert_almost_eq(r1, 100_000.0);
+        assert_almost_eq(r2, 0.5);
+    }
+
+    #[test]
+    fn footcandles() {
+        let i1 = Illuminance::from_footcandles(10.0);
+        let r1 = i1.as_lux();
+        let i2 = Illuminance::from_lux(500.0);
+        let r2 = i2.as_footcandles();
+        assert_almost_eq(r1, 107.639104);
+        assert_almost_eq(r2, 46.451520);
+    }
+
+    #[test]
+    fn appropriate_units() {
+        let i = Illuminance::from_lux(0.25);
+        assert_eq!(i.get_appropriate_units().0, "mlx");
+        let i = Illuminance::from_lux(25_000.0);
+        assert_eq!(i.get_appropriate_units().0, "klx");
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Illuminance::from_lux(2.0);
+        let b = Illuminance::from_lux(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_lux(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = Illuminance::from_lux(2.0);
+        let b = Illuminance::from_lux(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_lux(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = Illuminance::from_lux(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_lux(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = Illuminance::from_lux(2.0);
+        let b = Illuminance::from_lux(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_lux(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = Illuminance::from_lux(2.0);
+        let b = Illuminance::from_lux(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = Illuminance::from_lux(2.0);
+        let b = Illuminance::from_lux(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Illuminance::from_lux(2.0);
+        let b = Illuminance::from_lux(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
@@ -0,0 +1,209 @@
+//! Types and constants for handling electrical inductance.
+
+use super::measurement::*;
+use capacitance::Capacitance;
+use frequency::Frequency;
+use resistance::Resistance;
+use time;
+
+/// Units supported by [`Inductance`], as (symbol, long name, number of
+/// henries in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("nH", "nanohenries", 1e-9),
+    ("\u{00B5}H", "microhenries", 1e-6),
+    ("mH", "millihenries", 1e-3),
+    ("H", "henries", 1.0),
+];
+
+/// The `Inductance` struct can be used to deal with electrical inductance in
+/// a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Capacitance, Inductance};
+///
+/// let l = Inductance::from_millihenries(1.0);
+/// let f = l.resonant_frequency_with(Capacitance::from_microfarads(1.0));
+/// println!("The tank circuit resonates at {}.", f);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Inductance {
+    henries: f64,
+}
+
+impl Inductance {
+    /// Create a new Inductance from a floating point value in henries
+    pub fn from_henries(henries: f64) -> Self {
+        Inductance { henries }
+    }
+
+    /// Create a new Inductance from a floating point value in millihenries
+    pub fn from_millihenries(millihenries: f64) -> Self {
+        Self::from_henries(millihenries / 1e3)
+    }
+
+    /// Create a new Inductance from a floating point value in microhenries
+    pub fn from_microhenries(microhenries: f64) -> Self {
+        Self::from_henries(microhenries / 1e6)
+    }
+
+    /// Convert this Inductance into a floating point value in henries
+    pub fn as_henries(&self) -> f64 {
+        self.henries
+    }
+
+    /// Convert this Inductance into a floating point value in millihenries
+    pub fn as_millihenries(&self) -> f64 {
+        self.henries * 1e3
+    }
+
+    /// Convert this Inductance into a floating point value in microhenries
+    pub fn as_microhenries(&self) -> f64 {
+        self.henries * 1e6
+    }
+
+    /// Calculate the time constant of this Inductance in series with the
+    /// given Resistance (τ = L / R)
+    pub fn time_constant_with(&self, r: Resistance) -> time::Duration {
+        time::Duration::from_base_units(self.henries / r.as_ohms())
+    }
+
+    /// Calculate the resonant Frequency of this Inductance with the given
+    /// Capacitance (f = 1 / (2π √(LC)))
+    #[cfg(feature = "std")]
+    pub fn resonant_frequency_with(&self, c: Capacitance) -> Frequency {
+        Frequency::from_hertz(1.0 / (2.0 * ::PI * (self.henries * c.as_farads()).sqrt()))
+    }
+
+    /// Calculate the resonant Frequency of this Inductance with the given
+    /// Capacitance (f = 1 / (2π √(LC)))
+    #[cfg(not(feature = "std"))]
+    pub fn resonant_frequency_with(&self, c: Capacitance) -> Frequency {
+        Frequency::from_hertz(1.0 / (2.0 * ::PI * libm::sqrt(self.henries * c.as_farads())))
+    }
+}
+
+impl Measurement for Inductance {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.henries
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_henries(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "H"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [("nH", 1e-9), ("\u{00B5}H", 1e-6), ("mH", 1e-3), ("H", 1e0)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "Inductance"
+    }
+}
+
+implement_debug! { Inductance, henries }
+
+implement_measurement! { Inductance }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn millihenries() {
+        let i1 = Inductance::from_millihenries(2.2);
+        let r1 = i1.as_microhenries();
+        let i2 = Inductance::from_microhenries(470.0);
+        let r2 = i2.as_millihenries();
+        assert_almost_eq(r1, 2200.0);
+        assert_almost_eq(r2, 0.47);
+    }
+
+    #[test]
+    fn time_constant_with() {
+        let l = Inductance::from_millihenries(10.0);
+        let tau = l.time_constant_with(Resistance::from_ohms(100.0));
+        assert_almost_eq(tau.as_base_units(), 1e-4);
+    }
+
+    #[test]
+    fn resonant_frequency_with() {
+        let l = Inductance::from_millihenries(1.0);
+        let f = l.resonant_frequency_with(Capacitance::from_microfarads(1.0));
+        assert!((f.as_kilohertz() - 5.033).abs() < 0.001);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Inductance::from_henries(2.0);
+        let b = Inductance::from_henries(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_henries(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = Inductance::from_henries(2.0);
+        let b = Inductance::from_henries(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_henries(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = Inductance::from_henries(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_henries(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = Inductance::from_henries(2.0);
+        let b = Inductance::from_henries(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_henries(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = Inductance::from_henries(2.0);
+        let b = Inductance::from_henries(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = Inductance::from_henries(2.0);
+        let b = Inductance::from_henries(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Inductance::from_henries(2.0);
+        let b = Inductance::from_henries(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
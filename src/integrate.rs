@@ -0,0 +1,101 @@
+//! Functions for integrating rates (such as speed, or power) over time.
+
+use super::measurement::*;
+use std::ops::{Add, Mul};
+use time;
+
+/// Integrate a series of readings of a rate over time, where each reading is
+/// the average rate over the given period: Q = Σ Rᵢ × Δtᵢ.
+///
+/// This works for any rate which gives a quantity when multiplied by a
+/// `Duration`, e.g. `Speed` to `Length`, or `Power` to `Energy`.
+///
+/// # Example
+///
+/// ```
+/// use measurements::integrate::integrate_rate;
+/// use measurements::{Length, Speed};
+/// use std::time::Duration;
+///
+/// let trip = [
+///     (Speed::from_kilometers_per_hour(50.0), Duration::from_secs(600)),
+///     (Speed::from_kilometers_per_hour(100.0), Duration::from_secs(1800)),
+/// ];
+/// let distance: Length = integrate_rate(&trip);
+/// println!("The trip was {} long.", distance);
+/// ```
+pub fn integrate_rate<R, Q>(samples: &[(R, time::Duration)]) -> Q
+where
+    R: Mul<time::Duration, Output = Q> + Copy,
+    Q: Measurement + Add<Output = Q>,
+{
+    samples
+        .iter()
+        .fold(Q::from_base_units(0.0), |total, &(rate, period)| {
+            total + rate * period
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use energy::Energy;
+    use length::Length;
+    use power::Power;
+    use speed::Speed;
+    use test_utils::assert_almost_eq;
+    use volume::Volume;
+    use volumetric_flow_rate::VolumetricFlowRate;
+
+    #[test]
+    fn speed_to_distance() {
+        let samples = [
+            (
+                Speed::from_meters_per_second(10.0),
+                time::Duration::from_secs(30),
+            ),
+            (
+                Speed::from_meters_per_second(20.0),
+                time::Duration::from_secs(15),
+            ),
+            (
+                Speed::from_meters_per_second(0.0),
+                time::Duration::from_secs(60),
+            ),
+        ];
+        let distance: Length = integrate_rate(&samples);
+        assert_almost_eq(distance.as_meters(), 600.0);
+    }
+
+    #[test]
+    fn power_to_energy() {
+        let samples = [
+            (Power::from_kilowatts(2.0), time::Duration::from_secs(1800)),
+            (Power::from_kilowatts(1.0), time::Duration::from_secs(3600)),
+        ];
+        let energy: Energy = integrate_rate(&samples);
+        assert_almost_eq(energy.as_kilowatt_hours(), 2.0);
+    }
+
+    #[test]
+    fn flow_to_volume() {
+        let samples = [
+            (
+                VolumetricFlowRate::from_liters_per_minute(10.0),
+                time::Duration::from_secs(600),
+            ),
+            (
+                VolumetricFlowRate::from_liters_per_minute(20.0),
+                time::Duration::from_secs(300),
+            ),
+        ];
+        let volume: Volume = integrate_rate(&samples);
+        assert_almost_eq(volume.as_liters(), 200.0);
+    }
+
+    #[test]
+    fn no_samples() {
+        let distance: Length = integrate_rate::<Speed, Length>(&[]);
+        assert_eq!(distance.as_meters(), 0.0);
+    }
+}
@@ -1,6 +1,16 @@
 //! Types and constants for handling lengths (or distances).
 
 use super::measurement::*;
+use acceleration::Acceleration;
+use area::Area;
+use density::Density;
+use planetary::PlanetaryContext;
+use pressure::Pressure;
+use speed::Speed;
+use temperature::TemperatureDelta;
+use time;
+use volume::Volume;
+use PI;
 
 // Constants, metric
 
@@ -32,6 +42,24 @@ pub const METER_FURLONG_FACTOR: f64 = 10000.0 / (254.0 * 12.0 * 3.0 * 220.0);
 /// Number of miles in a meter
 pub const METER_MILE_FACTOR: f64 = 10000.0 / (254.0 * 12.0 * 3.0 * 1760.0);
 
+/// Units supported by [`Length`], as (symbol, long name, number of meters
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("nm", "nanometers", 1.0 / METER_NANOMETER_FACTOR),
+    ("\u{00B5}m", "micrometers", 1.0 / METER_MICROMETER_FACTOR),
+    ("mm", "millimeters", 1.0 / METER_MILLIMETER_FACTOR),
+    ("cm", "centimeters", 1.0 / METER_CENTIMETER_FACTOR),
+    ("dm", "decimeters", 1.0 / METER_DECIMETER_FACTOR),
+    ("m", "meters", 1.0),
+    ("hm", "hectometers", 1.0 / METER_HECTOMETER_FACTOR),
+    ("km", "kilometers", 1.0 / METER_KILOMETER_FACTOR),
+    ("in", "inches", 1.0 / METER_INCH_FACTOR),
+    ("ft", "feet", 1.0 / METER_FEET_FACTOR),
+    ("yd", "yards", 1.0 / METER_YARD_FACTOR),
+    ("fur", "furlongs", 1.0 / METER_FURLONG_FACTOR),
+    ("mi", "miles", 1.0 / METER_MILE_FACTOR),
+];
+
 /// The Length struct can be used to deal with lengths in a common way.
 /// Common metric and imperial units are supported.
 ///
@@ -45,7 +73,8 @@ pub const METER_MILE_FACTOR: f64 = 10000.0 / (254.0 * 12.0 * 3.0 * 1760.0);
 /// println!("There are {} meters in a football field.", meters);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Length {
     meters: f64,
 }
@@ -263,9 +292,117 @@ impl Length {
     pub fn as_miles(&self) -> f64 {
         self.meters * METER_MILE_FACTOR
     }
+
+    /// Calculate this Length after a change in temperature, given a linear
+    /// thermal expansion coefficient: L × (1 + α × ΔT). For steel,
+    /// `coefficient_per_kelvin` is about 0.000012.
+    pub fn thermal_expanded(&self, delta: TemperatureDelta, coefficient_per_kelvin: f64) -> Self {
+        Self::from_meters(self.meters * (1.0 + coefficient_per_kelvin * delta.as_kelvin()))
+    }
+
+    /// Calculate the pressure at this depth in a fluid with the given density,
+    /// under the given acceleration due to gravity (ρ × g × h). This is the
+    /// gauge pressure; add the pressure at the surface for the absolute
+    /// pressure.
+    pub fn hydrostatic_pressure(&self, fluid_density: Density, gravity: Acceleration) -> Pressure {
+        Pressure::from_pascals(
+            fluid_density.as_kilograms_per_cubic_meter()
+                * gravity.as_meters_per_second_per_second()
+                * self.meters,
+        )
+    }
+
+    /// Calculate the time taken to fall this height from rest under the given
+    /// acceleration due to gravity, ignoring air resistance (√(2h / g))
+    #[cfg(feature = "std")]
+    pub fn free_fall_time(&self, gravity: Acceleration) -> time::Duration {
+        time::Duration::from_base_units(
+            (2.0 * self.meters / gravity.as_meters_per_second_per_second()).sqrt(),
+        )
+    }
+
+    /// Calculate the time taken to fall this height from rest under the given
+    /// acceleration due to gravity, ignoring air resistance (√(2h / g))
+    #[cfg(not(feature = "std"))]
+    pub fn free_fall_time(&self, gravity: Acceleration) -> time::Duration {
+        time::Duration::from_base_units(libm::sqrt(
+            2.0 * self.meters / gravity.as_meters_per_second_per_second(),
+        ))
+    }
+
+    /// Calculate the Speed reached by falling this height from rest under the
+    /// given acceleration due to gravity, ignoring air resistance (√(2gh))
+    #[cfg(feature = "std")]
+    pub fn free_fall_velocity(&self, gravity: Acceleration) -> Speed {
+        Speed::from_meters_per_second(
+            (2.0 * gravity.as_meters_per_second_per_second() * self.meters).sqrt(),
+        )
+    }
+
+    /// Calculate the Speed reached by falling this height from rest under the
+    /// given acceleration due to gravity, ignoring air resistance (√(2gh))
+    #[cfg(not(feature = "std"))]
+    pub fn free_fall_velocity(&self, gravity: Acceleration) -> Speed {
+        Speed::from_meters_per_second(libm::sqrt(
+            2.0 * gravity.as_meters_per_second_per_second() * self.meters,
+        ))
+    }
+
+    /// Calculate the period of a simple pendulum of this length under the
+    /// given acceleration due to gravity, for small swings (2π √(L / g))
+    #[cfg(feature = "std")]
+    pub fn pendulum_period(&self, gravity: Acceleration) -> time::Duration {
+        time::Duration::from_base_units(
+            2.0 * PI * (self.meters / gravity.as_meters_per_second_per_second()).sqrt(),
+        )
+    }
+
+    /// Calculate the period of a simple pendulum of this length under the
+    /// given acceleration due to gravity, for small swings (2π √(L / g))
+    #[cfg(not(feature = "std"))]
+    pub fn pendulum_period(&self, gravity: Acceleration) -> time::Duration {
+        time::Duration::from_base_units(
+            2.0 * PI * libm::sqrt(self.meters / gravity.as_meters_per_second_per_second()),
+        )
+    }
+
+    /// Calculate the time taken to fall this height from rest at the surface
+    /// of the given planet or moon, ignoring air resistance
+    pub fn free_fall_time_on(&self, body: &PlanetaryContext) -> time::Duration {
+        self.free_fall_time(body.gravity())
+    }
+
+    /// Calculate the Speed reached by falling this height from rest at the
+    /// surface of the given planet or moon, ignoring air resistance
+    pub fn free_fall_velocity_on(&self, body: &PlanetaryContext) -> Speed {
+        self.free_fall_velocity(body.gravity())
+    }
+
+    /// Calculate the period of a simple pendulum of this length at the
+    /// surface of the given planet or moon, for small swings
+    pub fn pendulum_period_on(&self, body: &PlanetaryContext) -> time::Duration {
+        self.pendulum_period(body.gravity())
+    }
+
+    /// Calculate the circumference of a circle with the given radius (2πr)
+    pub fn circumference_from_radius(radius: Length) -> Self {
+        Self::from_meters(2.0 * PI * radius.meters)
+    }
+
+    /// Calculate the Area of a square with sides of this Length (L²)
+    pub fn squared(&self) -> Area {
+        Area::from_square_meters(self.meters * self.meters)
+    }
+
+    /// Calculate the Volume of a cube with edges of this Length (L³)
+    pub fn cubed(&self) -> Volume {
+        Volume::from_cubic_meters(self.meters * self.meters * self.meters)
+    }
 }
 
 impl Measurement for Length {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.meters
     }
@@ -293,8 +430,14 @@ impl Measurement for Length {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Length"
+    }
 }
 
+implement_debug! { Length, meters }
+
 implement_measurement! { Length }
 
 #[cfg(test)]
@@ -448,6 +591,142 @@ mod test {
         assert_almost_eq(r2, 160934.4);
     }
 
+    #[test]
+    fn units_table() {
+        for &(symbol, name, scale) in UNITS {
+            let from_name = match name {
+                "nanometers" => Length::from_nanometers(2.5),
+                "micrometers" => Length::from_micrometers(2.5),
+                "millimeters" => Length::from_millimeters(2.5),
+                "centimeters" => Length::from_centimeters(2.5),
+                "decimeters" => Length::from_decimeters(2.5),
+                "meters" => Length::from_meters(2.5),
+                "hectometers" => Length::from_hectometers(2.5),
+                "kilometers" => Length::from_kilometers(2.5),
+                "inches" => Length::from_inches(2.5),
+                "feet" => Length::from_feet(2.5),
+                "yards" => Length::from_yards(2.5),
+                "furlongs" => Length::from_furlongs(2.5),
+                "miles" => Length::from_miles(2.5),
+                _ => panic!("unexpected unit {}", symbol),
+            };
+            let from_scale = Length::from_base_units(2.5 * scale);
+            assert_almost_eq(from_scale.as_meters(), from_name.as_meters());
+            assert_almost_eq(from_scale.as_base_units() / scale, 2.5);
+        }
+    }
+
+    #[test]
+    fn round_sig() {
+        let l = Length::from_meters(12345.0);
+        assert_almost_eq(l.round_sig(2).as_meters(), 12000.0);
+        assert_almost_eq(l.round_sig(4).as_meters(), 12350.0);
+        let l = Length::from_meters(-0.0012345);
+        assert_almost_eq(l.round_sig(3).as_meters(), -0.00123);
+        assert_eq!(Length::from_meters(0.0).round_sig(3).as_meters(), 0.0);
+        assert_eq!(
+            Length::from_meters(1e-300).round_sig(10).as_meters(),
+            1e-300
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn round_sig_zero_figures() {
+        Length::from_meters(12345.0).round_sig(0);
+    }
+
+    #[test]
+    fn thermal_expanded() {
+        let rail = Length::from_meters(100.0);
+        let hot = rail.thermal_expanded(TemperatureDelta::from_celsius(40.0), 0.000012);
+        assert_almost_eq(hot.as_millimeters(), 100_048.0);
+    }
+
+    #[test]
+    fn circumference_from_radius() {
+        let c = Length::circumference_from_radius(Length::from_meters(1.0));
+        assert_almost_eq(c.as_meters(), 2.0 * PI);
+    }
+
+    #[test]
+    fn quantity_name() {
+        assert_eq!(Length::quantity_name(), "Length");
+    }
+
+    #[test]
+    fn hydrostatic_pressure() {
+        let water = Density::from_kilograms_per_cubic_meter(1000.0);
+        let p =
+            Length::from_meters(10.0).hydrostatic_pressure(water, ::constants::standard_gravity());
+        assert_almost_eq(p.as_kilopascals(), 98.0665);
+    }
+
+    #[test]
+    fn cmp_within() {
+        use std::cmp::Ordering;
+        let tolerance = Length::from_millimeters(1.0);
+        let a = Length::from_meters(2.0);
+        let b = Length::from_meters(2.0005);
+        let c = Length::from_meters(2.01);
+        assert_eq!(a.cmp_within(&b, tolerance), Ordering::Equal);
+        assert_eq!(b.cmp_within(&a, tolerance), Ordering::Equal);
+        assert_eq!(a.cmp_within(&c, tolerance), Ordering::Less);
+        assert_eq!(c.cmp_within(&a, tolerance), Ordering::Greater);
+    }
+
+    #[test]
+    fn cmp_within_nan() {
+        use std::cmp::Ordering;
+        let tolerance = Length::from_millimeters(1.0);
+        let a = Length::from_meters(2.0);
+        let nan = Length::from_meters(f64::NAN);
+        assert_eq!(a.cmp_within(&nan, tolerance), Ordering::Less);
+        assert_eq!(nan.cmp_within(&a, tolerance), Ordering::Greater);
+        assert_eq!(nan.cmp_within(&nan, tolerance), Ordering::Equal);
+    }
+
+    #[test]
+    fn free_fall() {
+        let h = Length::from_meters(20.0);
+        let g = ::constants::standard_gravity();
+        let t = h.free_fall_time(g).as_base_units();
+        assert!((t - 2.02).abs() < 0.005);
+        let v = h.free_fall_velocity(g).as_meters_per_second();
+        assert!((v - 19.8).abs() < 0.05);
+    }
+
+    #[test]
+    fn pendulum_period() {
+        let l = Length::from_meters(1.0);
+        let t = l
+            .pendulum_period(::constants::standard_gravity())
+            .as_base_units();
+        assert!((t - 2.006).abs() < 0.001);
+    }
+
+    #[test]
+    fn squared_and_cubed() {
+        let l = Length::from_meters(3.0);
+        assert_almost_eq(l.squared().as_square_meters(), 9.0);
+        assert_almost_eq(l.cubed().as_cubic_meters(), 27.0);
+        let l = Length::from_centimeters(10.0);
+        assert_almost_eq(l.cubed().as_liters(), 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn all_units() {
+        let units = Length::from_meters(1.0).all_units();
+        assert_eq!(units.len(), UNITS.len());
+        let find = |symbol| units.iter().find(|&&(s, _)| s == symbol).unwrap().1;
+        assert_almost_eq(find("km"), 0.001);
+        assert_almost_eq(find("mm"), 1000.0);
+        assert!(Length::from_meters(1.0)
+            .unit_values()
+            .any(|(s, v)| s == "m" && v == 1.0));
+    }
+
     // Traits
     #[test]
     fn add() {
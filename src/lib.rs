@@ -8,6 +8,7 @@
 
 #![deny(warnings, missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
 
 #[cfg(not(feature = "std"))]
 use core as std;
@@ -21,14 +22,24 @@ use std::time;
 #[macro_use]
 extern crate serde;
 
-#[cfg(feature = "from_str")]
-extern crate regex;
-
 use std::f64::consts::PI;
 
 #[macro_use]
 mod measurement;
+#[doc(hidden)]
+pub use measurement::round_significant;
+#[cfg(feature = "sig_fig_display")]
+#[doc(hidden)]
+pub use measurement::rounded_display_units;
+#[cfg(feature = "std")]
+pub use measurement::GroupedDisplay;
 pub use measurement::Measurement;
+pub use measurement::UnitValues;
+
+/// The number of significant figures a measurement is displayed to when no
+/// precision is given, with the `sig_fig_display` feature.
+#[cfg(feature = "sig_fig_display")]
+pub const DISPLAY_SIGNIFICANT_FIGURES: u32 = 6;
 
 pub mod length;
 pub use length::{Distance, Length};
@@ -48,6 +59,24 @@ pub use volume::Volume;
 pub mod density;
 pub use density::Density;
 
+pub mod amount_of_substance;
+pub use amount_of_substance::AmountOfSubstance;
+
+pub mod molar_mass;
+pub use molar_mass::MolarMass;
+
+pub mod concentration;
+pub use concentration::Concentration;
+
+pub mod mass_concentration;
+pub use mass_concentration::MassConcentration;
+
+pub mod mass_flow_rate;
+pub use mass_flow_rate::MassFlowRate;
+
+pub mod volumetric_flow_rate;
+pub use volumetric_flow_rate::VolumetricFlowRate;
+
 pub mod pressure;
 pub use pressure::Pressure;
 
@@ -60,6 +89,9 @@ pub use acceleration::Acceleration;
 pub mod energy;
 pub use energy::Energy;
 
+pub mod specific_energy;
+pub use specific_energy::SpecificEnergy;
+
 pub mod power;
 pub use power::Power;
 
@@ -69,17 +101,29 @@ pub use voltage::Voltage;
 pub mod current;
 pub use current::Current;
 
+pub mod charge;
+pub use charge::Charge;
+
 pub mod resistance;
 pub use resistance::Resistance;
 
+pub mod capacitance;
+pub use capacitance::Capacitance;
+
+pub mod inductance;
+pub use inductance::Inductance;
+
 pub mod force;
 pub use force::Force;
 
 pub mod area;
 pub use area::Area;
 
+pub mod area_density;
+pub use area_density::AreaDensity;
+
 pub mod angle;
-pub use angle::Angle;
+pub use angle::{Angle, AngleAccumulator};
 
 pub mod frequency;
 pub use frequency::Frequency;
@@ -93,106 +137,181 @@ pub use torque::Torque;
 pub mod data;
 pub use data::Data;
 
-mod torque_energy;
-pub use torque_energy::TorqueEnergy;
+pub mod data_rate;
+pub use data_rate::DataRate;
 
-pub mod prelude;
+pub mod ratio;
+pub use ratio::Ratio;
 
-pub mod test_utils;
+pub mod percentage;
+pub use percentage::Percentage;
 
-/// For given types A, B and C, implement, using base units:
-///     - A = B * C
-///     - A = C * B
-///     - B = A / C
-///     - C = A / B
-macro_rules! impl_maths {
-    ($a:ty, $b:ty) => {
-        impl std::ops::Mul<$b> for $b {
-            type Output = $a;
-
-            fn mul(self, rhs: $b) -> Self::Output {
-                Self::Output::from_base_units(self.as_base_units() * rhs.as_base_units())
-            }
-        }
+pub mod fuel_economy;
+pub use fuel_economy::FuelEconomy;
 
-        impl std::ops::Div<$b> for $a {
-            type Output = $b;
+pub mod illuminance;
+pub use illuminance::Illuminance;
 
-            fn div(self, rhs: $b) -> Self::Output {
-                Self::Output::from_base_units(self.as_base_units() / rhs.as_base_units())
-            }
-        }
-    };
+pub mod exposure;
+pub use exposure::Exposure;
 
-    ($a:ty, $b:ty, $c:ty) => {
-        impl std::ops::Mul<$b> for $c {
-            type Output = $a;
+pub mod sortable;
+pub use sortable::SortableMeasurement;
 
-            fn mul(self, rhs: $b) -> Self::Output {
-                Self::Output::from_base_units(self.as_base_units() * rhs.as_base_units())
-            }
-        }
+mod torque_energy;
+pub use torque_energy::TorqueEnergy;
 
-        impl std::ops::Mul<$c> for $b {
-            type Output = $a;
+pub mod constants;
 
-            fn mul(self, rhs: $c) -> Self::Output {
-                Self::Output::from_base_units(self.as_base_units() * rhs.as_base_units())
-            }
-        }
+pub mod planetary;
+pub use planetary::PlanetaryContext;
 
-        impl std::ops::Div<$c> for $a {
-            type Output = $b;
+#[cfg(feature = "from_str")]
+pub mod parse;
+#[cfg(feature = "from_str")]
+pub use parse::ParseMeasurementError;
 
-            fn div(self, rhs: $c) -> Self::Output {
-                Self::Output::from_base_units(self.as_base_units() / rhs.as_base_units())
-            }
-        }
+pub mod range;
 
-        impl std::ops::Div<$b> for $a {
-            type Output = $c;
+pub mod lookup;
 
-            fn div(self, rhs: $b) -> Self::Output {
-                Self::Output::from_base_units(self.as_base_units() / rhs.as_base_units())
-            }
-        }
-    };
-}
+pub mod integrate;
+
+pub mod series;
+pub use series::MeasurementIterator;
+
+pub mod prelude;
+
+pub mod test_utils;
 
 impl Measurement for time::Duration {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = &[
+        ("ns", "nanoseconds", 1e-9),
+        ("\u{00B5}s", "microseconds", 1e-6),
+        ("ms", "milliseconds", 1e-3),
+        ("s", "seconds", 1.0),
+        ("min", "minutes", 60.0),
+        ("h", "hours", 3600.0),
+        ("d", "days", 86400.0),
+    ];
+
     fn as_base_units(&self) -> f64 {
         self.as_secs() as f64 + (f64::from(self.subsec_nanos()) * 1e-9)
     }
 
     fn from_base_units(units: f64) -> Self {
-        let subsec_nanos = ((units * 1e9) % 1e9) as u32;
-        let secs = units as u64;
+        // A Duration can't be negative (or NaN), so those saturate to zero,
+        // and one too long for a Duration saturates to the longest there is.
+        if units.is_nan() || units <= 0.0 {
+            return time::Duration::new(0, 0);
+        }
+        if units >= u64::MAX as f64 {
+            return time::Duration::new(u64::MAX, 999_999_999);
+        }
+        // Take the whole seconds first, so the nanoseconds come from the
+        // fractional part alone rather than from a scaled-up total which
+        // runs out of precision for large values.
+        let mut secs = units as u64;
+        let mut subsec_nanos = ((units - secs as f64) * 1e9 + 0.5) as u32;
+        if subsec_nanos >= 1_000_000_000 {
+            secs += 1;
+            subsec_nanos = 0;
+        }
         time::Duration::new(secs, subsec_nanos)
     }
 
     fn get_base_units_name(&self) -> &'static str {
         "s"
     }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to largest
+        let list = [
+            ("ns", 1e-9),
+            ("\u{00B5}s", 1e-6),
+            ("ms", 1e-3),
+            ("s", 1.0),
+            ("min", 60.0),
+            ("h", 3600.0),
+            ("d", 86400.0),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "Duration"
+    }
+}
+
+/// As `Duration` is defined in the standard library, it can't implement
+/// `Display` here. This wrapper displays a `Duration` in the most appropriate
+/// unit, like any other measurement.
+///
+/// # Example
+///
+/// ```
+/// use measurements::DurationDisplay;
+/// use std::time::Duration;
+///
+/// let cooking = Duration::from_secs(90);
+/// println!("Cook for {}.", DurationDisplay(cooking));
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DurationDisplay(pub time::Duration);
+
+implement_display!(DurationDisplay);
+
+impl Measurement for DurationDisplay {
+    const UNITS: &'static [(&'static str, &'static str, f64)] =
+        <time::Duration as Measurement>::UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.0.as_base_units()
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        DurationDisplay(time::Duration::from_base_units(units))
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        self.0.get_base_units_name()
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        self.0.get_appropriate_units()
+    }
+
+    fn quantity_name() -> &'static str {
+        "Duration"
+    }
 }
 
-impl_maths!(Area, Length);
-impl_maths!(Energy, time::Duration, Power);
-impl_maths!(Force, Mass, Acceleration);
-impl_maths!(Force, Pressure, Area);
-impl_maths!(Length, time::Duration, Speed);
-impl_maths!(Power, Force, Speed);
-impl_maths!(Speed, time::Duration, Acceleration);
-impl_maths!(Volume, Length, Area);
-impl_maths!(Power, AngularVelocity, Torque);
-impl_maths!(Power, Voltage, Current);
-impl_maths!(Voltage, Resistance, Current);
+relate_measurements!(Area, Length);
+relate_measurements!(Energy, time::Duration, Power);
+relate_measurements!(Force, Mass, Acceleration);
+relate_measurements!(Force, Pressure, Area);
+relate_measurements!(Length, time::Duration, Speed);
+relate_measurements!(Power, Force, Speed);
+relate_measurements!(Speed, time::Duration, Acceleration);
+relate_measurements!(Volume, Length, Area);
+relate_measurements!(Mass, AreaDensity, Area);
+relate_measurements!(Exposure, time::Duration, Illuminance);
+relate_measurements!(Power, AngularVelocity, Torque);
+relate_measurements!(Power, Voltage, Current);
+relate_measurements!(Voltage, Resistance, Current);
+relate_measurements!(Charge, time::Duration, Current);
+relate_measurements!(Mass, AmountOfSubstance, MolarMass);
+relate_measurements!(Energy, SpecificEnergy, Mass);
+relate_measurements!(Mass, MassFlowRate, time::Duration);
+relate_measurements!(Power, MassFlowRate, SpecificEnergy);
+relate_measurements!(Data, DataRate, time::Duration);
 
 // Force * Distance is ambiguous. Create an ambiguous struct the user can then
 // cast into either Torque or Energy.
 
-impl_maths!(TorqueEnergy, Force, Length);
+relate_measurements!(TorqueEnergy, Force, Length);
 
-// Implement the divisions manually (the above macro only implemented the
+// Implement the divisions manually (`relate_measurements!` only implemented the
 // TorqueEnergy / X operations).
 
 impl std::ops::Div<Length> for Torque {
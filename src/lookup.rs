@@ -0,0 +1,166 @@
+//! Lookup tables for interpolating between calibration points, such as a
+//! thermistor's resistance against temperature.
+
+use super::measurement::*;
+
+/// A table of points mapping one measurement to another, linearly
+/// interpolated in base units between the points. The table borrows its
+/// points, so it can be built from a `const` array without allocating.
+///
+/// # Example
+///
+/// ```
+/// use measurements::lookup::LookupTable;
+/// use measurements::{Resistance, Temperature};
+///
+/// let points = [
+///     (Resistance::from_kiloohms(32.6), Temperature::from_celsius(0.0)),
+///     (Resistance::from_kiloohms(10.0), Temperature::from_celsius(25.0)),
+///     (Resistance::from_kiloohms(3.6), Temperature::from_celsius(50.0)),
+/// ];
+/// let table = LookupTable::new(&points).unwrap();
+/// let reading = Resistance::from_kiloohms(12.0);
+/// println!("The thermistor is at {}.", table.get(reading));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct LookupTable<'a, X: 'a, Y: 'a> {
+    points: &'a [(X, Y)],
+}
+
+impl<'a, X: Measurement, Y: Measurement> LookupTable<'a, X, Y> {
+    /// Create a new LookupTable from the given points, which must be ordered
+    /// by their key, either ascending or descending. Returns `None` if there
+    /// are no points, or if they are not ordered.
+    pub fn new(points: &'a [(X, Y)]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let ascending = points
+            .windows(2)
+            .all(|w| w[0].0.as_base_units() <= w[1].0.as_base_units());
+        let descending = points
+            .windows(2)
+            .all(|w| w[0].0.as_base_units() >= w[1].0.as_base_units());
+        if ascending || descending {
+            Some(LookupTable { points })
+        } else {
+            None
+        }
+    }
+
+    /// Look up the value for the given key, interpolating between the two
+    /// nearest points. A key outside the table gives the value at the
+    /// nearest end of the table.
+    pub fn get(&self, x: X) -> Y {
+        let x = x.as_base_units();
+        self.interpolate(x).unwrap_or_else(|| {
+            let (ref x0, ref y0) = self.points[0];
+            let (ref x1, ref y1) = self.points[self.points.len() - 1];
+            let before_first = (x - x0.as_base_units()) * (x1.as_base_units() - x0.as_base_units());
+            if before_first < 0.0 {
+                Y::from_base_units(y0.as_base_units())
+            } else {
+                Y::from_base_units(y1.as_base_units())
+            }
+        })
+    }
+
+    /// Look up the value for the given key, interpolating between the two
+    /// nearest points. Returns `None` if the key is outside the table, e.g. to
+    /// reject a reading that the calibration doesn't cover.
+    pub fn get_strict(&self, x: X) -> Option<Y> {
+        self.interpolate(x.as_base_units())
+    }
+
+    fn interpolate(&self, x: f64) -> Option<Y> {
+        if self.points.len() == 1 {
+            let (ref x0, ref y0) = self.points[0];
+            return if x == x0.as_base_units() {
+                Some(Y::from_base_units(y0.as_base_units()))
+            } else {
+                None
+            };
+        }
+        self.points.windows(2).find_map(|w| {
+            let (x0, y0) = (w[0].0.as_base_units(), w[0].1.as_base_units());
+            let (x1, y1) = (w[1].0.as_base_units(), w[1].1.as_base_units());
+            let within = (x0 <= x && x <= x1) || (x1 <= x && x <= x0);
+            if !within {
+                None
+            } else if x0 == x1 {
+                Some(Y::from_base_units(y0))
+            } else {
+                let t = (x - x0) / (x1 - x0);
+                Some(Y::from_base_units(y0 + (y1 - y0) * t))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use resistance::Resistance;
+    use temperature::Temperature;
+    use test_utils::assert_almost_eq;
+
+    fn points() -> [(Resistance, Temperature); 3] {
+        [
+            (
+                Resistance::from_kiloohms(30.0),
+                Temperature::from_celsius(0.0),
+            ),
+            (
+                Resistance::from_kiloohms(10.0),
+                Temperature::from_celsius(25.0),
+            ),
+            (
+                Resistance::from_kiloohms(4.0),
+                Temperature::from_celsius(50.0),
+            ),
+        ]
+    }
+
+    #[test]
+    fn get() {
+        let points = points();
+        let table = LookupTable::new(&points).unwrap();
+        let t = table.get(Resistance::from_kiloohms(20.0));
+        assert_almost_eq(t.as_celsius(), 12.5);
+        let t = table.get(Resistance::from_kiloohms(10.0));
+        assert_almost_eq(t.as_celsius(), 25.0);
+        let t = table.get(Resistance::from_kiloohms(7.0));
+        assert_almost_eq(t.as_celsius(), 37.5);
+    }
+
+    #[test]
+    fn get_clamps() {
+        let points = points();
+        let table = LookupTable::new(&points).unwrap();
+        let t = table.get(Resistance::from_kiloohms(40.0));
+        assert_eq!(t.as_celsius(), 0.0);
+        let t = table.get(Resistance::from_kiloohms(1.0));
+        assert_almost_eq(t.as_celsius(), 50.0);
+    }
+
+    #[test]
+    fn get_strict() {
+        let points = points();
+        let table = LookupTable::new(&points).unwrap();
+        let t = table.get_strict(Resistance::from_kiloohms(20.0)).unwrap();
+        assert_almost_eq(t.as_celsius(), 12.5);
+        let t = table.get_strict(Resistance::from_kiloohms(4.0)).unwrap();
+        assert_almost_eq(t.as_celsius(), 50.0);
+        assert_eq!(table.get_strict(Resistance::from_kiloohms(40.0)), None);
+        assert_eq!(table.get_strict(Resistance::from_kiloohms(1.0)), None);
+    }
+
+    #[test]
+    fn new_rejects_unordered() {
+        let empty: [(Resistance, Temperature); 0] = [];
+        assert!(LookupTable::new(&empty).is_none());
+        let mut points = points();
+        points.swap(0, 1);
+        assert!(LookupTable::new(&points).is_none());
+    }
+}
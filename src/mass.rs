@@ -1,10 +1,17 @@
 //! Types and constants for handling masses.
 
 use super::measurement::*;
+use acceleration::Acceleration;
+use constants;
+use density::{Density, WATER_KGCM};
+use force::Force;
+use length::Length;
 #[cfg(feature = "from_str")]
-use regex::Regex;
+use parse::{parse_number, split_number_unit, unknown_unit, ParseMeasurementError};
+use planetary::PlanetaryContext;
 #[cfg(feature = "from_str")]
 use std::str::FromStr;
+use volume::Volume;
 
 // Constants, metric
 
@@ -42,6 +49,26 @@ pub const KILOGRAM_SHORT_TONS_FACTOR: f64 = KILOGRAM_POUNDS_FACTOR / 2000.0;
 /// Number of Long (international) Tons in a kg
 pub const KILOGRAM_LONG_TONS_FACTOR: f64 = KILOGRAM_POUNDS_FACTOR / 2240.0;
 
+/// Units supported by [`Mass`], as (symbol, long name, number of kilograms
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("\u{00B5}g", "micrograms", 1.0 / KILOGRAM_MICROGRAM_FACTOR),
+    ("mg", "milligrams", 1.0 / KILOGRAM_MILLIGRAM_FACTOR),
+    ("ct", "carats", 1.0 / KILOGRAM_CARAT_FACTOR),
+    ("g", "grams", 1.0 / KILOGRAM_GRAM_FACTOR),
+    ("kg", "kilograms", 1.0),
+    ("t", "tonnes", 1.0 / KILOGRAM_TONNE_FACTOR),
+    ("gr", "grains", 1.0 / KILOGRAM_GRAINS_FACTOR),
+    ("dwt", "pennyweights", 1.0 / KILOGRAM_PENNYWEIGHTS_FACTOR),
+    ("oz", "ounces", 1.0 / KILOGRAM_OUNCES_FACTOR),
+    ("lb", "pounds", 1.0 / KILOGRAM_POUNDS_FACTOR),
+    ("oz t", "troy ounces", 1.0 / KILOGRAM_TROY_OUNCES_FACTOR),
+    ("lb t", "troy pounds", 1.0 / KILOGRAM_TROY_POUNDS_FACTOR),
+    ("st", "stones", 1.0 / KILOGRAM_STONES_FACTOR),
+    ("sh tn", "short tons", 1.0 / KILOGRAM_SHORT_TONS_FACTOR),
+    ("long tn", "long tons", 1.0 / KILOGRAM_LONG_TONS_FACTOR),
+];
+
 /// The Mass struct can be used to deal with mass in a common way. Metric,
 /// avoirdupois imperial and troy imperial units are supported.
 ///
@@ -58,7 +85,8 @@ pub const KILOGRAM_LONG_TONS_FACTOR: f64 = KILOGRAM_POUNDS_FACTOR / 2240.0;
 ///     united_states_tons, united_states_pounds);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Mass {
     kilograms: f64,
 }
@@ -144,6 +172,11 @@ impl Mass {
         Self::from_kilograms(long_tons / KILOGRAM_LONG_TONS_FACTOR)
     }
 
+    /// Create a Mass from the volume of water it takes up (at 1000 kg/m³)
+    pub fn of_water(volume: Volume) -> Self {
+        volume * Density::from_kilograms_per_cubic_meter(WATER_KGCM)
+    }
+
     /// Convert this Mass to a floating point value in micrograms
     pub fn as_micrograms(&self) -> f64 {
         self.kilograms * KILOGRAM_MICROGRAM_FACTOR
@@ -223,9 +256,40 @@ impl Mass {
     pub fn as_long_tons(&self) -> f64 {
         self.kilograms * KILOGRAM_LONG_TONS_FACTOR
     }
+
+    /// Calculate the weight of this Mass under the given acceleration due to
+    /// gravity (m × g)
+    pub fn weight(&self, gravity: Acceleration) -> Force {
+        *self * gravity
+    }
+
+    /// Calculate the weight of this Mass under standard gravity at the
+    /// Earth's surface
+    pub fn weight_on_earth(&self) -> Force {
+        self.weight(constants::standard_gravity())
+    }
+
+    /// Calculate the weight of this Mass at the surface of the given planet
+    /// or moon
+    pub fn weight_on(&self, body: &PlanetaryContext) -> Force {
+        self.weight(body.gravity())
+    }
+
+    /// Create a new Mass from its weight under the given acceleration due to
+    /// gravity (m = F / g), e.g. for a scale which measures force. Returns
+    /// `None` if the gravity is zero.
+    pub fn from_weight(force: Force, gravity: Acceleration) -> Option<Self> {
+        if gravity.as_meters_per_second_per_second() == 0.0 {
+            None
+        } else {
+            Some(force / gravity)
+        }
+    }
 }
 
 impl Measurement for Mass {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.kilograms
     }
@@ -252,11 +316,15 @@ impl Measurement for Mass {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Mass"
+    }
 }
 
 #[cfg(feature = "from_str")]
 impl FromStr for Mass {
-    type Err = std::num::ParseFloatError;
+    type Err = ParseMeasurementError;
 
     /// Create a new Mass from a string
     /// Plain numbers in string are considered to be Kilograms
@@ -265,33 +333,49 @@ impl FromStr for Mass {
             return Ok(Mass::from_kilograms(0.0));
         }
 
-        let re = Regex::new(r"(?i)\s*([0-9.]*)\s?([a-zμ]{1,3})\s*$").unwrap();
-        if let Some(caps) = re.captures(val) {
-            let float_val = caps.get(1).unwrap().as_str();
-            return Ok(
-                match caps.get(2).unwrap().as_str().to_lowercase().as_str() {
-                    "ug" | "μg" => Mass::from_micrograms(float_val.parse::<f64>()?),
-                    "mg" => Mass::from_milligrams(float_val.parse::<f64>()?),
-                    "ct" => Mass::from_carats(float_val.parse::<f64>()?),
-                    "g" => Mass::from_grams(float_val.parse::<f64>()?),
-                    "kg" => Mass::from_kilograms(float_val.parse::<f64>()?),
-                    "t" => Mass::from_metric_tons(float_val.parse::<f64>()?),
-                    "gr" => Mass::from_grains(float_val.parse::<f64>()?),
-                    "dwt" => Mass::from_pennyweights(float_val.parse::<f64>()?),
-                    "oz" => Mass::from_ounces(float_val.parse::<f64>()?),
-                    "st" => Mass::from_stones(float_val.parse::<f64>()?),
-                    "lb" | "lbs" => Mass::from_pounds(float_val.parse::<f64>()?),
-                    _ => Mass::from_grams(float_val.parse::<f64>()?),
-                },
-            );
-        }
-
-        Ok(Mass::from_kilograms(val.parse::<f64>()?))
+        let (number, unit) = split_number_unit(val);
+        Ok(match unit.to_lowercase().as_str() {
+            "" => Mass::from_kilograms(parse_number(number)?),
+            "ug" | "\u{00B5}g" | "μg" => Mass::from_micrograms(parse_number(number)?),
+            "mg" => Mass::from_milligrams(parse_number(number)?),
+            "ct" => Mass::from_carats(parse_number(number)?),
+            "g" => Mass::from_grams(parse_number(number)?),
+            "kg" => Mass::from_kilograms(parse_number(number)?),
+            "t" => Mass::from_metric_tons(parse_number(number)?),
+            "gr" => Mass::from_grains(parse_number(number)?),
+            "dwt" => Mass::from_pennyweights(parse_number(number)?),
+            "oz" => Mass::from_ounces(parse_number(number)?),
+            "st" => Mass::from_stones(parse_number(number)?),
+            "lb" | "lbs" => Mass::from_pounds(parse_number(number)?),
+            _ => return Err(unknown_unit(&unit)),
+        })
     }
 }
 
+implement_debug! { Mass, kilograms }
+
 implement_measurement! { Mass }
 
+/// Calculate the body mass index (BMI) of a person of the given mass and
+/// height, in kg/m². A height of zero (or less) gives NaN.
+///
+/// # Example
+///
+/// ```
+/// use measurements::mass::body_mass_index;
+/// use measurements::{Length, Mass};
+///
+/// let bmi = body_mass_index(Mass::from_kilograms(70.0), Length::from_meters(1.75));
+/// println!("BMI: {:.1}", bmi);
+/// ```
+pub fn body_mass_index(mass: Mass, height: Length) -> f64 {
+    let meters = height.as_meters();
+    if meters <= 0.0 {
+        return f64::NAN;
+    }
+    mass.as_kilograms() / (meters * meters)
+}
+
 #[cfg(test)]
 mod test {
     use mass::*;
@@ -461,6 +545,42 @@ mod test {
         assert_almost_eq(o, 1016.0469);
     }
 
+    #[test]
+    fn of_water() {
+        let m = Mass::of_water(Volume::from_liters(1.0));
+        assert_almost_eq(m.as_kilograms(), 1.0);
+    }
+
+    #[test]
+    fn body_mass_index() {
+        let bmi = super::body_mass_index(Mass::from_kilograms(70.0), Length::from_meters(1.75));
+        assert_almost_eq(bmi, 22.857142857);
+        let bmi = super::body_mass_index(Mass::from_pounds(154.0), Length::from_inches(69.0));
+        assert_almost_eq(bmi, 22.741591);
+    }
+
+    #[test]
+    fn body_mass_index_zero_height() {
+        let bmi = super::body_mass_index(Mass::from_kilograms(70.0), Length::from_meters(0.0));
+        assert!(bmi.is_nan());
+    }
+
+    #[test]
+    fn weight() {
+        let m = Mass::from_kilograms(10.0);
+        assert_almost_eq(m.weight_on_earth().as_newtons(), 98.0665);
+        let mars = Acceleration::from_meters_per_second_per_second(3.78);
+        assert_almost_eq(m.weight(mars).as_newtons(), 37.8);
+    }
+
+    #[test]
+    fn from_weight() {
+        let m = Mass::from_weight(Force::from_newtons(98.07), constants::standard_gravity());
+        assert!((m.unwrap().as_kilograms() - 10.0).abs() < 0.001);
+        let zero = Acceleration::from_meters_per_second_per_second(0.0);
+        assert_eq!(Mass::from_weight(Force::from_newtons(98.07), zero), None);
+    }
+
     // Traits
     #[test]
     fn add() {
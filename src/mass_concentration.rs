@@ -0,0 +1,245 @@
+//! Types and constants for handling mass concentrations.
+
+use super::measurement::*;
+use mass::Mass;
+use volume::Volume;
+
+/// Units supported by [`MassConcentration`], as (symbol, long name, number of
+/// kilograms per cubic meter in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("\u{00B5}g/m\u{00B3}", "micrograms per cubic meter", 1e-9),
+    ("mg/m\u{00B3}", "milligrams per cubic meter", 1e-6),
+    ("g/m\u{00B3}", "grams per cubic meter", 1e-3),
+    ("mg/L", "milligrams per liter", 1e-3),
+    ("kg/m\u{00B3}", "kilograms per cubic meter", 1.0),
+];
+
+/// The `MassConcentration` struct can be used to deal with the mass of a
+/// solute or pollutant per volume of the mixture it is in, such as particulate
+/// matter in air.
+///
+/// Although it has the same units as `Density`, it is kept
+/// as a separate type, as it describes one part of a mixture rather than the
+/// mixture as a whole.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Mass, MassConcentration, Volume};
+///
+/// let pm25 = MassConcentration::from_micrograms_per_cubic_meter(12.0);
+/// let inhaled: Mass = pm25 * Volume::from_cubic_meters(11.0);
+/// println!("A day's breathing takes in {}.", inhaled);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct MassConcentration {
+    kilograms_per_cubic_meter: f64,
+}
+
+impl MassConcentration {
+    /// Create a new MassConcentration from a floating point value in kilograms per cubic meter
+    pub fn from_kilograms_per_cubic_meter(kilograms_per_cubic_meter: f64) -> Self {
+        MassConcentration {
+            kilograms_per_cubic_meter,
+        }
+    }
+
+    /// Create a new MassConcentration from a floating point value in milligrams per cubic meter
+    pub fn from_milligrams_per_cubic_meter(milligrams_per_cubic_meter: f64) -> Self {
+        Self::from_kilograms_per_cubic_meter(milligrams_per_cubic_meter / 1_000_000.0)
+    }
+
+    /// Create a new MassConcentration from a floating point value in micrograms per cubic meter
+    pub fn from_micrograms_per_cubic_meter(micrograms_per_cubic_meter: f64) -> Self {
+        Self::from_kilograms_per_cubic_meter(micrograms_per_cubic_meter / 1_000_000_000.0)
+    }
+
+    /// Create a new MassConcentration from a floating point value in milligrams per liter
+    pub fn from_milligrams_per_liter(milligrams_per_liter: f64) -> Self {
+        Self::from_kilograms_per_cubic_meter(milligrams_per_liter / 1000.0)
+    }
+
+    /// Create a new MassConcentration of a pollutant Mass spread through a
+    /// carrier Volume. (Dividing a Mass by a Volume gives a Density instead.)
+    pub fn of(pollutant: Mass, carrier: Volume) -> Self {
+        Self::from_kilograms_per_cubic_meter(pollutant.as_kilograms() / carrier.as_cubic_meters())
+    }
+
+    /// Convert this MassConcentration into a floating point value in kilograms per cubic meter
+    pub fn as_kilograms_per_cubic_meter(&self) -> f64 {
+        self.kilograms_per_cubic_meter
+    }
+
+    /// Convert this MassConcentration into a floating point value in milligrams per cubic meter
+    pub fn as_milligrams_per_cubic_meter(&self) -> f64 {
+        self.kilograms_per_cubic_meter * 1_000_000.0
+    }
+
+    /// Convert this MassConcentration into a floating point value in micrograms per cubic meter
+    pub fn as_micrograms_per_cubic_meter(&self) -> f64 {
+        self.kilograms_per_cubic_meter * 1_000_000_000.0
+    }
+
+    /// Convert this MassConcentration into a floating point value in milligrams per liter
+    pub fn as_milligrams_per_liter(&self) -> f64 {
+        self.kilograms_per_cubic_meter * 1000.0
+    }
+}
+
+// mass / mass concentration = volume
+impl ::std::ops::Div<MassConcentration> for Mass {
+    type Output = Volume;
+
+    fn div(self, other: MassConcentration) -> Volume {
+        Volume::from_cubic_meters(self.as_base_units() / other.as_base_units())
+    }
+}
+
+// volume * mass concentration = mass
+impl ::std::ops::Mul<MassConcentration> for Volume {
+    type Output = Mass;
+
+    fn mul(self, other: MassConcentration) -> Mass {
+        Mass::from_base_units(self.as_cubic_meters() * other.as_base_units())
+    }
+}
+
+// mass concentration * volume = mass
+impl ::std::ops::Mul<Volume> for MassConcentration {
+    type Output = Mass;
+
+    fn mul(self, other: Volume) -> Mass {
+        Mass::from_base_units(self.as_base_units() * other.as_cubic_meters())
+    }
+}
+
+impl Measurement for MassConcentration {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.kilograms_per_cubic_meter
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_kilograms_per_cubic_meter(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "kg/m\u{00B3}"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [
+            ("\u{00B5}g/m\u{00B3}", 1e-9),
+            ("mg/m\u{00B3}", 1e-6),
+            ("g/m\u{00B3}", 1e-3),
+            ("kg/m\u{00B3}", 1e0),
+        ];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "MassConcentration"
+    }
+}
+
+implement_debug! { MassConcentration, kilograms_per_cubic_meter }
+
+implement_measurement! { MassConcentration }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn milligrams_per_cubic_meter() {
+        let i1 = MassConcentration::from_milligrams_per_cubic_meter(5.0);
+        let r1 = i1.as_kilograms_per_cubic_meter();
+        let i2 = MassConcentration::from_micrograms_per_cubic_meter(2500.0);
+        let r2 = i2.as_milligrams_per_cubic_meter();
+        let i3 = MassConcentration::from_milligrams_per_liter(1.0);
+        let r3 = i3.as_milligrams_per_cubic_meter();
+        assert_almost_eq(r1, 5e-6);
+        assert_almost_eq(r2, 2.5);
+        assert_almost_eq(r3, 1000.0);
+    }
+
+    #[test]
+    fn mass_in_volume() {
+        let c = MassConcentration::from_milligrams_per_cubic_meter(5.0);
+        let m: Mass = c * Volume::from_cubic_meters(2.0);
+        assert_almost_eq(m.as_milligrams(), 10.0);
+        let m: Mass = Volume::from_cubic_meters(2.0) * c;
+        assert_almost_eq(m.as_milligrams(), 10.0);
+        let c = MassConcentration::of(Mass::from_milligrams(10.0), Volume::from_cubic_meters(2.0));
+        assert_almost_eq(c.as_milligrams_per_cubic_meter(), 5.0);
+        let v: Volume = Mass::from_milligrams(10.0) / c;
+        assert_almost_eq(v.as_cubic_meters(), 2.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = MassConcentration::from_kilograms_per_cubic_meter(2.0);
+        let b = MassConcentration::from_kilograms_per_cubic_meter(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_kilograms_per_cubic_meter(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = MassConcentration::from_kilograms_per_cubic_meter(2.0);
+        let b = MassConcentration::from_kilograms_per_cubic_meter(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_kilograms_per_cubic_meter(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = MassConcentration::from_kilograms_per_cubic_meter(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_kilograms_per_cubic_meter(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = MassConcentration::from_kilograms_per_cubic_meter(2.0);
+        let b = MassConcentration::from_kilograms_per_cubic_meter(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_kilograms_per_cubic_meter(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = MassConcentration::from_kilograms_per_cubic_meter(2.0);
+        let b = MassConcentration::from_kilograms_per_cubic_meter(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = MassConcentration::from_kilograms_per_cubic_meter(2.0);
+        let b = MassConcentration::from_kilograms_per_cubic_meter(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = MassConcentration::from_kilograms_per_cubic_meter(2.0);
+        let b = MassConcentration::from_kilograms_per_cubic_meter(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
@@ -0,0 +1,200 @@
+//! Types and constants for handling mass flow rates.
+
+use super::measurement::*;
+use power::Power;
+use specific_energy::SpecificEnergy;
+
+/// Units supported by [`MassFlowRate`], as (symbol, long name, number of
+/// kilograms per second in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("g/s", "grams per second", 1e-3),
+    ("kg/h", "kilograms per hour", 1.0 / 3600.0),
+    ("kg/s", "kilograms per second", 1.0),
+];
+
+/// The `MassFlowRate` struct can be used to deal with the rate at which mass
+/// flows, such as the fuel flow into an engine, in a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{MassFlowRate, SpecificEnergy};
+///
+/// let fuel = MassFlowRate::from_grams_per_second(2.0);
+/// let power = fuel.power_output(SpecificEnergy::from_megajoules_per_kilogram(42.6));
+/// println!("The burner releases {}.", power);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct MassFlowRate {
+    kilograms_per_second: f64,
+}
+
+impl MassFlowRate {
+    /// Create a new MassFlowRate from a floating point value in kilograms per second
+    pub fn from_kilograms_per_second(kilograms_per_second: f64) -> Self {
+        MassFlowRate {
+            kilograms_per_second,
+        }
+    }
+
+    /// Create a new MassFlowRate from a floating point value in grams per second
+    pub fn from_grams_per_second(grams_per_second: f64) -> Self {
+        Self::from_kilograms_per_second(grams_per_second / 1000.0)
+    }
+
+    /// Create a new MassFlowRate from a floating point value in kilograms per hour
+    pub fn from_kilograms_per_hour(kilograms_per_hour: f64) -> Self {
+        Self::from_kilograms_per_second(kilograms_per_hour / 3600.0)
+    }
+
+    /// Convert this MassFlowRate into a floating point value in kilograms per second
+    pub fn as_kilograms_per_second(&self) -> f64 {
+        self.kilograms_per_second
+    }
+
+    /// Convert this MassFlowRate into a floating point value in grams per second
+    pub fn as_grams_per_second(&self) -> f64 {
+        self.kilograms_per_second * 1000.0
+    }
+
+    /// Convert this MassFlowRate into a floating point value in kilograms per hour
+    pub fn as_kilograms_per_hour(&self) -> f64 {
+        self.kilograms_per_second * 3600.0
+    }
+
+    /// Calculate the thermal Power released by burning a fuel flowing at this
+    /// rate, given its heating value. Use the lower heating value (LHV) for
+    /// the heat actually available from most engines and burners.
+    pub fn power_output(&self, heating_value: SpecificEnergy) -> Power {
+        *self * heating_value
+    }
+}
+
+impl Measurement for MassFlowRate {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.kilograms_per_second
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_kilograms_per_second(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "kg/s"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [("mg/s", 1e-6), ("g/s", 1e-3), ("kg/s", 1e0)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "MassFlowRate"
+    }
+}
+
+implement_debug! { MassFlowRate, kilograms_per_second }
+
+implement_measurement! { MassFlowRate }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mass::Mass;
+    use test_utils::assert_almost_eq;
+    use time;
+
+    #[test]
+    fn grams_per_second() {
+        let i1 = MassFlowRate::from_grams_per_second(500.0);
+        let r1 = i1.as_kilograms_per_second();
+        let i2 = MassFlowRate::from_kilograms_per_hour(36.0);
+        let r2 = i2.as_grams_per_second();
+        assert_almost_eq(r1, 0.5);
+        assert_almost_eq(r2, 10.0);
+    }
+
+    #[test]
+    fn power_output() {
+        // 2 g/s of diesel at an LHV of 42.6 MJ/kg
+        let fuel = MassFlowRate::from_grams_per_second(2.0);
+        let power = fuel.power_output(SpecificEnergy::from_megajoules_per_kilogram(42.6));
+        assert_almost_eq(power.as_kilowatts(), 85.2);
+    }
+
+    #[test]
+    fn mass_over_time() {
+        let fuel = MassFlowRate::from_kilograms_per_hour(18.0);
+        let m: Mass = fuel * time::Duration::from_secs(1800);
+        assert_almost_eq(m.as_kilograms(), 9.0);
+        let f: MassFlowRate = m / time::Duration::from_secs(1800);
+        assert_almost_eq(f.as_kilograms_per_hour(), 18.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = MassFlowRate::from_kilograms_per_second(2.0);
+        let b = MassFlowRate::from_kilograms_per_second(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_kilograms_per_second(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = MassFlowRate::from_kilograms_per_second(2.0);
+        let b = MassFlowRate::from_kilograms_per_second(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_kilograms_per_second(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = MassFlowRate::from_kilograms_per_second(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_kilograms_per_second(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = MassFlowRate::from_kilograms_per_second(2.0);
+        let b = MassFlowRate::from_kilograms_per_second(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_kilograms_per_second(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = MassFlowRate::from_kilograms_per_second(2.0);
+        let b = MassFlowRate::from_kilograms_per_second(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = MassFlowRate::from_kilograms_per_second(2.0);
+        let b = MassFlowRate::from_kilograms_per_second(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = MassFlowRate::from_kilograms_per_second(2.0);
+        let b = MassFlowRate::from_kilograms_per_second(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
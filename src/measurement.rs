@@ -1,51 +1,50 @@
-/// The `Measurement` trait and the `implement_measurement!` macro
-/// provides a common way for various measurements to be implemented.
-///
-/// # Example
-/// ```
-/// #![no_std]
-/// // Importing the `implement_measurement` macro from the external crate is important
-/// #[macro_use]
-/// extern crate measurements;
-///
-/// use measurements::Measurement;
-///
-/// struct Cubits {
-///     forearms: f64
-/// }
-///
-/// impl Measurement for Cubits {
-///     fn as_base_units(&self) -> f64 {
-///         self.forearms
-///     }
-///
-///     fn from_base_units(units: f64) -> Self {
-///         Cubits { forearms: units }
-///     }
-///
-///    fn get_base_units_name(&self) -> &'static str {
-///        "cu"
-///    }
-/// }
-///
-/// // Invoke the macro to automatically implement Add, Sub, etc...
-/// implement_measurement! { Cubits }
-///
-/// // The main function here is only included to make doc test_utils compile.
-/// // You should't need it in your own code.
-/// fn main() { }
-/// ```
-
-#[cfg(feature = "no-std")]
-use core as std;
-
-#[cfg(feature = "no-std")]
-use core::num::Float;
+//! The `Measurement` trait and the `implement_measurement!` macro
+//! provides a common way for various measurements to be implemented.
+//!
+//! # Example
+//! ```
+//! // Importing the `implement_measurement` macro from the external crate is important
+//! #[macro_use]
+//! extern crate measurements;
+//!
+//! use measurements::Measurement;
+//!
+//! struct Cubits {
+//!     forearms: f64
+//! }
+//!
+//! impl Measurement for Cubits {
+//!     fn as_base_units(&self) -> f64 {
+//!         self.forearms
+//!     }
+//!
+//!     fn from_base_units(units: f64) -> Self {
+//!         Cubits { forearms: units }
+//!     }
+//!
+//!    fn get_base_units_name(&self) -> &'static str {
+//!        "cu"
+//!    }
+//! }
+//!
+//! // Invoke the macro to automatically implement Add, Sub, etc...
+//! implement_measurement! { Cubits }
+//!
+//! // The main function here is only included to make doc test_utils compile.
+//! // You should't need it in your own code.
+//! fn main() { }
+//! ```
 
 /// All measurements implement this.
 ///
 /// It provides conversion functions to and from raw numbers.
 pub trait Measurement {
+    /// The units this quantity can be converted to with `value_in` and
+    /// `unit_values`, as
+    /// (symbol, long name, number of base units in one unit) triples. This is
+    /// empty unless the implementation gives a table.
+    const UNITS: &'static [(&'static str, &'static str, f64)] = &[];
+
     /// Returns a string containing the most appropriate units for this quantity,
     /// and a floating point value representing this quantity in those units.
     /// Useful when, for example, a length might be in millimeters if it is very small,
@@ -62,7 +61,13 @@ pub trait Measurement {
     ///
     /// The list must be smallest to largest, e.g. ("nanometre", 10-9) to
     /// ("kilometre", 10e3)
+    ///
+    /// Negative values are selected by their magnitude, keeping their sign.
+    /// Zero (or an empty list) gives the base unit.
     fn pick_appropriate_units(&self, list: &[(&'static str, f64)]) -> (&'static str, f64) {
+        if list.is_empty() || self.as_base_units() == 0.0 {
+            return (self.get_base_units_name(), self.as_base_units());
+        }
         for &(unit, ref scale) in list.iter().rev() {
             let value = self.as_base_units() / scale;
             if value >= 1.0 || value <= -1.0 {
@@ -72,6 +77,211 @@ pub trait Measurement {
         (list[0].0, self.as_base_units() / list[0].1)
     }
 
+    /// Given a list of units and their scale relative to the base unit,
+    /// select the most appropriate one, taking into account rounding the
+    /// value to the given number of significant figures.
+    ///
+    /// Unlike `pick_appropriate_units`, a value which rounds up to the next
+    /// unit is shown in that unit, e.g. 999.95 m to four significant figures
+    /// is 1 km, not 999.9 m. The returned value is rounded.
+    ///
+    /// Negative values are selected by their magnitude, keeping their sign.
+    /// Zero (or an empty list) gives the base unit.
+    ///
+    /// The list must be smallest to largest, e.g. ("nanometre", 10-9) to
+    /// ("kilometre", 10e3)
+    fn pick_appropriate_units_rounded(
+        &self,
+        list: &[(&'static str, f64)],
+        sig_figs: u32,
+    ) -> (&'static str, f64) {
+        if list.is_empty() || self.as_base_units() == 0.0 {
+            return (self.get_base_units_name(), self.as_base_units());
+        }
+        for &(unit, ref scale) in list.iter().rev() {
+            let value = round_significant(self.as_base_units() / scale, sig_figs);
+            if value >= 1.0 || value <= -1.0 {
+                return (unit, value);
+            }
+        }
+        (
+            list[0].0,
+            round_significant(self.as_base_units() / list[0].1, sig_figs),
+        )
+    }
+
+    /// Returns this quantity with its value in base units rounded to the
+    /// given number of significant figures, e.g. 12345 m to two significant
+    /// figures is 12000 m.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `figures` is zero.
+    fn round_sig(&self, figures: u32) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(
+            figures > 0,
+            "round_sig needs at least one significant figure"
+        );
+        Self::from_base_units(round_significant(self.as_base_units(), figures))
+    }
+
+    /// Returns the given percentage of this quantity, e.g. 75% of 100 W is
+    /// 75 W. The quantity is scaled in its base units.
+    fn scaled_by_percent(&self, percent: f64) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_base_units(self.as_base_units() * (percent / 100.0))
+    }
+
+    /// Splits this quantity into whether it is negative and its magnitude,
+    /// e.g. to show a sign or arrow next to the size of a change. The sign is
+    /// that of the value in base units, so a `Temperature` below 0 °C is
+    /// still positive (in kelvin), but a negative `TemperatureDelta` is not.
+    /// Negative zero and NaN are not reported as negative.
+    fn split_sign(&self) -> (bool, Self)
+    where
+        Self: Sized,
+    {
+        let value = self.as_base_units();
+        let negative = value < 0.0;
+        (
+            negative,
+            Self::from_base_units(if negative { -value } else { value }),
+        )
+    }
+
+    /// Converts this quantity to a floating point value in the unit with the
+    /// given symbol from [`Measurement::UNITS`], or `None` if there is no such
+    /// unit.
+    fn value_in(&self, unit: &str) -> Option<f64> {
+        Self::UNITS
+            .iter()
+            .find(|&&(symbol, _, _)| symbol == unit)
+            .map(|&(_, _, factor)| self.as_base_units() / factor)
+    }
+
+    /// Returns the value of this quantity in each unit in
+    /// [`Measurement::UNITS`], as (symbol, value) pairs.
+    fn unit_values(&self) -> UnitValues {
+        UnitValues {
+            base_units: self.as_base_units(),
+            units: Self::UNITS.iter(),
+        }
+    }
+
+    /// Returns the value of this quantity in each unit in
+    /// [`Measurement::UNITS`], as (symbol, value) pairs, collected into a
+    /// `Vec`.
+    #[cfg(feature = "std")]
+    fn all_units(&self) -> Vec<(&'static str, f64)> {
+        self.unit_values().collect()
+    }
+
+    /// Returns this quantity as a fraction of the given magnitude, e.g. one
+    /// component of a force relative to the total force. Unlike dividing
+    /// the two, a zero magnitude gives 0.0 rather than NaN or infinity.
+    fn component_ratio(&self, magnitude: &Self) -> f64 {
+        let magnitude = magnitude.as_base_units();
+        if magnitude == 0.0 {
+            0.0
+        } else {
+            self.as_base_units() / magnitude
+        }
+    }
+
+    /// Compares this quantity with another, treating them as `Equal` if they
+    /// differ by no more than `tolerance`, so that tiny differences between
+    /// readings don't change their order. Otherwise, they are ordered by
+    /// their value in base units, with NaN ordered after everything else.
+    fn cmp_within(&self, other: &Self, tolerance: Self) -> ::std::cmp::Ordering
+    where
+        Self: Sized,
+    {
+        let a = self.as_base_units();
+        let b = other.as_base_units();
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => return ::std::cmp::Ordering::Equal,
+            (true, false) => return ::std::cmp::Ordering::Greater,
+            (false, true) => return ::std::cmp::Ordering::Less,
+            (false, false) => {}
+        }
+        let diff = a - b;
+        let tolerance = tolerance.as_base_units();
+        if diff <= tolerance && -diff <= tolerance {
+            ::std::cmp::Ordering::Equal
+        } else if diff < 0.0 {
+            ::std::cmp::Ordering::Less
+        } else {
+            ::std::cmp::Ordering::Greater
+        }
+    }
+
+    /// Returns this quantity limited to the given Span, e.g. to keep a
+    /// reading within a sensor's operating range. The comparison is made in
+    /// base units.
+    fn clamp_to(&self, span: &::range::Span<Self>) -> Self
+    where
+        Self: Sized,
+    {
+        let value = self.as_base_units();
+        let min = span.min.as_base_units();
+        let max = span.max.as_base_units();
+        Self::from_base_units(if value < min {
+            min
+        } else if value > max {
+            max
+        } else {
+            value
+        })
+    }
+
+    /// Returns a wrapper which displays this quantity in the same way as
+    /// `Display`, but with commas separating the thousands in the value,
+    /// e.g. "1,500,000\u{00A0}m". A precision given to the formatter is
+    /// applied to the value.
+    #[cfg(feature = "std")]
+    fn display_grouped(&self) -> GroupedDisplay<'_, Self>
+    where
+        Self: Sized,
+    {
+        GroupedDisplay { measurement: self }
+    }
+
+    /// Writes this quantity to `w` in the same way as `Display`, e.g.
+    /// "1.5\u{00A0}km", without needing an allocator. This is handy for
+    /// writing into a fixed-size buffer such as a `heapless::String`.
+    fn write_to<W: ::std::fmt::Write>(&self, w: &mut W) -> ::std::fmt::Result
+    where
+        Self: Sized,
+    {
+        #[cfg(not(feature = "sig_fig_display"))]
+        let (unit, value) = self.get_appropriate_units();
+        #[cfg(feature = "sig_fig_display")]
+        let (unit, value) = rounded_display_units(self);
+        write!(w, "{}\u{00A0}{}", value, unit)
+    }
+
+    /// Returns the name of the quantity this type measures, such as "Length"
+    /// or "Pressure", for use in logs and error messages.
+    ///
+    /// The crate's own types, and those made with `define_measurement!`, give
+    /// their type name. The default implementation makes a best guess from
+    /// `std::any::type_name`, without the module path or any generic
+    /// parameters, but that isn't guaranteed to be stable, so implement this
+    /// if you rely on the name.
+    fn quantity_name() -> &'static str
+    where
+        Self: Sized,
+    {
+        let name = ::std::any::type_name::<Self>();
+        let name = name.split('<').next().unwrap_or(name);
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
     /// Return the base unit for this type, as a string.
     /// For example "kilograms"
     fn get_base_units_name(&self) -> &'static str;
@@ -83,8 +293,149 @@ pub trait Measurement {
     fn from_base_units(units: f64) -> Self;
 }
 
+/// Displays a measurement with thousands separators. Created by
+/// `Measurement::display_grouped`.
+#[cfg(feature = "std")]
+pub struct GroupedDisplay<'a, M: 'a> {
+    measurement: &'a M,
+}
+
+#[cfg(feature = "std")]
+impl<'a, M: Measurement> ::std::fmt::Display for GroupedDisplay<'a, M> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        #[cfg(not(feature = "sig_fig_display"))]
+        let (unit, value) = self.measurement.get_appropriate_units();
+        #[cfg(feature = "sig_fig_display")]
+        let (unit, value) = match f.precision() {
+            Some(_) => self.measurement.get_appropriate_units(),
+            None => rounded_display_units(self.measurement),
+        };
+        let value = match f.precision() {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => format!("{}", value),
+        };
+        let (sign, digits) = if value.starts_with('-') {
+            value.split_at(1)
+        } else {
+            ("", &value[..])
+        };
+        let (integer, fraction) = match digits.find('.') {
+            Some(point) => digits.split_at(point),
+            None => (digits, ""),
+        };
+        f.write_str(sign)?;
+        for (i, digit) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        write!(f, "{}\u{00A0}{}", fraction, unit)
+    }
+}
+
+/// Picks the units a measurement is displayed in with the `sig_fig_display`
+/// feature, rounding to `DISPLAY_SIGNIFICANT_FIGURES`. The value is rounded
+/// before the unit is chosen, so 999.9999999 m is "1 km" rather than
+/// "1000 m".
+#[cfg(feature = "sig_fig_display")]
+#[doc(hidden)]
+pub fn rounded_display_units<M: Measurement>(measurement: &M) -> (&'static str, f64) {
+    let figures = ::DISPLAY_SIGNIFICANT_FIGURES;
+    let (unit, value) = measurement.get_appropriate_units();
+    let rounded = M::from_base_units(round_significant(measurement.as_base_units(), figures));
+    let (rounded_unit, rounded_value) = rounded.get_appropriate_units();
+    // Keep the unrounded value when the unit doesn't change, so that units
+    // with an offset, like degrees Celsius, don't lose precision.
+    if rounded_unit == unit {
+        (unit, round_significant(value, figures))
+    } else {
+        (rounded_unit, round_significant(rounded_value, figures))
+    }
+}
+
+/// Round a value to the given number of significant figures.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn round_significant(value: f64, figures: u32) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    let exponent = figures as i32 - 1 - magnitude;
+    let rounded = if exponent < 0 {
+        // Divide rather than multiply by a factor below 1, which would lose
+        // precision for large values.
+        let factor = 10f64.powf(f64::from(-exponent));
+        (value / factor).round() * factor
+    } else {
+        let factor = 10f64.powf(f64::from(exponent));
+        if !factor.is_finite() {
+            return value;
+        }
+        (value * factor).round() / factor
+    };
+    if rounded.is_finite() {
+        rounded
+    } else {
+        value
+    }
+}
+
+/// Round a value to the given number of significant figures.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+pub fn round_significant(value: f64, figures: u32) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+    let magnitude = libm::floor(libm::log10(libm::fabs(value))) as i32;
+    let exponent = figures as i32 - 1 - magnitude;
+    let rounded = if exponent < 0 {
+        // Divide rather than multiply by a factor below 1, which would lose
+        // precision for large values.
+        let factor = libm::pow(10.0, f64::from(-exponent));
+        libm::round(value / factor) * factor
+    } else {
+        let factor = libm::pow(10.0, f64::from(exponent));
+        if !factor.is_finite() {
+            return value;
+        }
+        libm::round(value * factor) / factor
+    };
+    if rounded.is_finite() {
+        rounded
+    } else {
+        value
+    }
+}
+
+/// An iterator over the value of a measurement in each unit in its
+/// [`Measurement::UNITS`] table, as (symbol, value) pairs. Created by
+/// `Measurement::unit_values`.
+#[derive(Clone, Debug)]
+pub struct UnitValues {
+    base_units: f64,
+    units: ::std::slice::Iter<'static, (&'static str, &'static str, f64)>,
+}
+
+impl Iterator for UnitValues {
+    type Item = (&'static str, f64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.units
+            .next()
+            .map(|&(symbol, _, factor)| (symbol, self.base_units / factor))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.units.size_hint()
+    }
+}
+
 /// This is a special macro that creates the code to implement
 /// `std::fmt::Display`.
+#[cfg(not(feature = "sig_fig_display"))]
 #[macro_export]
 macro_rules! implement_display {
     ($($t:ty)*) => ($(
@@ -100,12 +451,115 @@ macro_rules! implement_display {
 }
 
 /// This is a special macro that creates the code to implement
-/// operator and comparison overrides.
+/// `std::fmt::Display`. When no precision is given, the value is rounded to
+/// `DISPLAY_SIGNIFICANT_FIGURES` significant figures before the unit is
+/// chosen.
+#[cfg(feature = "sig_fig_display")]
 #[macro_export]
-macro_rules! implement_measurement {
+macro_rules! implement_display {
     ($($t:ty)*) => ($(
 
-        implement_display!( $t );
+        impl ::std::fmt::Display for $t {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                let (unit, value) = if f.precision().is_none() {
+                    $crate::rounded_display_units(self)
+                } else {
+                    self.get_appropriate_units()
+                };
+                value.fmt(f)?;
+                write!(f, "\u{00A0}{}", unit)
+            }
+        }
+    )*)
+}
+
+/// This is a special macro that creates the code to implement
+/// dividing a measurement by another of the same type, which gives a bare
+/// `f64` ratio.
+#[cfg(not(feature = "typed_ratio"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implement_ratio_division {
+    ($t:ty) => {
+        impl ::std::ops::Div<$t> for $t {
+            type Output = f64;
+
+            fn div(self, rhs: Self) -> f64 {
+                self.as_base_units() / rhs.as_base_units()
+            }
+        }
+    };
+}
+
+/// This is a special macro that creates the code to implement
+/// dividing a measurement by another of the same type, which gives a
+/// dimensionless `Ratio`.
+#[cfg(feature = "typed_ratio")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implement_ratio_division {
+    ($t:ty) => {
+        impl ::std::ops::Div<$t> for $t {
+            type Output = $crate::Ratio;
+
+            fn div(self, rhs: Self) -> $crate::Ratio {
+                $crate::Ratio::from_ratio(self.as_base_units() / rhs.as_base_units())
+            }
+        }
+    };
+}
+
+/// This is a special macro that creates the code to implement
+/// `std::fmt::Debug`, showing the value of the given field just like
+/// `#[derive(Debug)]` would.
+#[cfg(not(feature = "debug_display"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implement_debug {
+    ($t:ident, $field:ident) => {
+        impl ::std::fmt::Debug for $t {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_struct(stringify!($t))
+                    .field(stringify!($field), &self.$field)
+                    .finish()
+            }
+        }
+    };
+}
+
+/// This is a special macro that creates the code to implement
+/// `std::fmt::Debug`, showing the value of the given field along with the
+/// `Display` form, e.g. `Pressure { pascals: 101325.0, display: "101.325 kPa" }`.
+#[cfg(feature = "debug_display")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! implement_debug {
+    ($t:ident, $field:ident) => {
+        impl ::std::fmt::Debug for $t {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                // Shows the `Display` form quoted, like a string would be.
+                struct Quoted<'a>(&'a $t);
+
+                impl<'a> ::std::fmt::Debug for Quoted<'a> {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        write!(f, "\"{}\"", self.0)
+                    }
+                }
+
+                f.debug_struct(stringify!($t))
+                    .field(stringify!($field), &self.$field)
+                    .field("display", &Quoted(self))
+                    .finish()
+            }
+        }
+    };
+}
+
+/// This is a special macro that creates the code to implement
+/// addition and subtraction of a measurement, and scaling it by a factor.
+#[macro_export]
+macro_rules! implement_arithmetic {
+    ($($t:ty)*) => ($(
 
         impl ::std::ops::Add for $t {
             type Output = Self;
@@ -123,16 +577,6 @@ macro_rules! implement_measurement {
             }
         }
 
-        // Dividing a `$t` by another `$t` returns a ratio.
-        //
-        impl ::std::ops::Div<$t> for $t {
-            type Output = f64;
-
-            fn div(self, rhs: Self) -> f64 {
-                self.as_base_units() / rhs.as_base_units()
-            }
-        }
-
         // Dividing a `$t` by a factor returns a new portion of the measurement.
         //
         impl ::std::ops::Div<f64> for $t {
@@ -161,6 +605,68 @@ macro_rules! implement_measurement {
                 rhs * self
             }
         }
+    )*)
+}
+
+/// This is a special macro that creates the code to implement
+/// operator and comparison overrides.
+#[macro_export]
+macro_rules! implement_measurement {
+    ($($t:ty)*) => ($(
+
+        implement_display!( $t );
+
+        implement_arithmetic!( $t );
+
+        // Dividing a `$t` by another `$t` returns a ratio.
+        //
+        implement_ratio_division!( $t );
+
+        // Dividing a `$t` by a dimensionless ratio returns a new portion of
+        // the measurement.
+        impl ::std::ops::Div<$crate::Ratio> for $t {
+            type Output = Self;
+
+            fn div(self, rhs: $crate::Ratio) -> Self {
+                Self::from_base_units(self.as_base_units() / rhs.as_ratio())
+            }
+        }
+
+        // Multiplying a `$t` by a dimensionless ratio scales that measurement.
+        impl ::std::ops::Mul<$crate::Ratio> for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: $crate::Ratio) -> Self {
+                Self::from_base_units(self.as_base_units() * rhs.as_ratio())
+            }
+        }
+
+        // Multiplying `$t` by a ratio is commutative
+        impl ::std::ops::Mul<$t> for $crate::Ratio {
+            type Output = $t;
+
+            fn mul(self, rhs: $t) -> $t {
+                rhs * self
+            }
+        }
+
+        // Multiplying a `$t` by a percentage scales that measurement.
+        impl ::std::ops::Mul<$crate::Percentage> for $t {
+            type Output = Self;
+
+            fn mul(self, rhs: $crate::Percentage) -> Self {
+                Self::from_base_units(self.as_base_units() * rhs.as_ratio())
+            }
+        }
+
+        // Multiplying `$t` by a percentage is commutative
+        impl ::std::ops::Mul<$t> for $crate::Percentage {
+            type Output = $t;
+
+            fn mul(self, rhs: $t) -> $t {
+                rhs * self
+            }
+        }
 
         impl ::std::cmp::Eq for $t { }
         impl ::std::cmp::PartialEq for $t {
@@ -176,3 +682,244 @@ macro_rules! implement_measurement {
         }
     )*)
 }
+
+/// Defines a new measurement type, for quantities this crate doesn't have.
+///
+/// This generates a `Copy` struct holding the value in base units, the
+/// `Measurement` implementation (picking display units from the given list),
+/// and the same `Display`, `Debug`, arithmetic and comparison implementations
+/// as the crate's own types. The units are given as (symbol, number of base
+/// units in one unit) pairs, smallest to largest as for
+/// `pick_appropriate_units`, and are available as `Measurement::UNITS`.
+///
+/// The generated code calls the other macros of this crate by name and uses
+/// `std` paths, so it needs `#[macro_use] extern crate measurements;` and
+/// `Measurement` in scope.
+///
+/// # Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate measurements;
+///
+/// use measurements::Measurement;
+///
+/// define_measurement! {
+///     /// A dose of radiation, in sieverts.
+///     name: Dose,
+///     base: "Sv",
+///     units: [("\u{00B5}Sv", 0.000_001), ("mSv", 0.001), ("Sv", 1.0)]
+/// }
+///
+/// fn main() {
+///     let x_ray = Dose::from_unit(20.0, "\u{00B5}Sv").unwrap();
+///     let total = x_ray * 3.0 + Dose::from_base_units(0.001);
+///     assert_eq!(total.value_in("\u{00B5}Sv"), Some(1060.0));
+///     assert_eq!(Dose::UNITS[1], ("mSv", "mSv", 0.001));
+///     assert_eq!(Dose::quantity_name(), "Dose");
+///     assert_eq!(format!("{:.2}", total), "1.06\u{00A0}mSv");
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_measurement {
+    (
+        $(#[$attr:meta])*
+        name: $name:ident,
+        base: $base:expr,
+        units: [$(($symbol:expr, $factor:expr)),+ $(,)*] $(,)*
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Default)]
+        pub struct $name {
+            base_units: f64,
+        }
+
+        impl $name {
+            /// Create a new value from a floating point value in the unit with
+            /// the given symbol, or `None` if there is no such unit
+            pub fn from_unit(value: f64, unit: &str) -> Option<Self> {
+                <Self as $crate::Measurement>::UNITS
+                    .iter()
+                    .find(|&&(symbol, _, _)| symbol == unit)
+                    .map(|&(_, _, factor)| $name { base_units: value * factor })
+            }
+        }
+
+        impl $crate::Measurement for $name {
+            // The symbol stands in for the long name, which isn't given.
+            const UNITS: &'static [(&'static str, &'static str, f64)] =
+                &[$(($symbol, $symbol, $factor)),+];
+
+            fn as_base_units(&self) -> f64 {
+                self.base_units
+            }
+
+            fn from_base_units(units: f64) -> Self {
+                $name { base_units: units }
+            }
+
+            fn get_base_units_name(&self) -> &'static str {
+                $base
+            }
+
+            fn get_appropriate_units(&self) -> (&'static str, f64) {
+                self.pick_appropriate_units(&[$(($symbol, $factor)),+])
+            }
+
+            fn quantity_name() -> &'static str {
+                stringify!($name)
+            }
+        }
+
+        implement_debug! { $name, base_units }
+
+        implement_measurement! { $name }
+    };
+}
+
+/// For given measurement types A, B and C, implements, using base units:
+///
+/// - A = B * C
+/// - A = C * B
+/// - B = A / C
+/// - C = A / B
+///
+/// or, with two types A and B, A = B * B and B = A / B.
+///
+/// This is how the crate relates its own types (e.g. Force, Mass and
+/// Acceleration), and it can be used to relate new types made with
+/// [`define_measurement!`] to each other or to the crate's types.
+///
+/// Rust's orphan rules only allow an operator to be implemented in the crate
+/// that defines one of the types involved, so if A is one of this crate's
+/// types then B and C must both be your own. Relationships between three of
+/// this crate's types can't be added outside it, and neither can ones which
+/// clash with an operator this crate already implements.
+///
+/// # Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate measurements;
+///
+/// use measurements::Measurement;
+/// use std::time::Duration;
+///
+/// define_measurement! {
+///     /// A dose of radiation, in sieverts.
+///     name: Dose,
+///     base: "Sv",
+///     units: [("\u{00B5}Sv", 0.000_001), ("mSv", 0.001), ("Sv", 1.0)]
+/// }
+///
+/// define_measurement! {
+///     /// A dose rate, in sieverts per second.
+///     name: DoseRate,
+///     base: "Sv/s",
+///     units: [("\u{00B5}Sv/h", 0.000_001 / 3600.0), ("Sv/s", 1.0)]
+/// }
+///
+/// relate_measurements!(Dose, DoseRate, Duration);
+///
+/// fn main() {
+///     let rate = DoseRate::from_unit(0.25, "\u{00B5}Sv/h").unwrap();
+///     let dose: Dose = rate * Duration::from_secs(8 * 3600);
+///     assert!((dose.value_in("\u{00B5}Sv").unwrap() - 2.0).abs() < 1e-9);
+///     let back: DoseRate = dose / Duration::from_secs(8 * 3600);
+///     assert!((back.value_in("\u{00B5}Sv/h").unwrap() - 0.25).abs() < 1e-9);
+/// }
+/// ```
+#[macro_export]
+macro_rules! relate_measurements {
+    ($a:ty, $b:ty) => {
+        impl ::std::ops::Mul<$b> for $b {
+            type Output = $a;
+
+            fn mul(self, rhs: $b) -> Self::Output {
+                <$a as $crate::Measurement>::from_base_units(
+                    $crate::Measurement::as_base_units(&self)
+                        * $crate::Measurement::as_base_units(&rhs),
+                )
+            }
+        }
+
+        impl ::std::ops::Div<$b> for $a {
+            type Output = $b;
+
+            fn div(self, rhs: $b) -> Self::Output {
+                <$b as $crate::Measurement>::from_base_units(
+                    $crate::Measurement::as_base_units(&self)
+                        / $crate::Measurement::as_base_units(&rhs),
+                )
+            }
+        }
+    };
+
+    ($a:ty, $b:ty, $c:ty) => {
+        impl ::std::ops::Mul<$b> for $c {
+            type Output = $a;
+
+            fn mul(self, rhs: $b) -> Self::Output {
+                <$a as $crate::Measurement>::from_base_units(
+                    $crate::Measurement::as_base_units(&self)
+                        * $crate::Measurement::as_base_units(&rhs),
+                )
+            }
+        }
+
+        impl ::std::ops::Mul<$c> for $b {
+            type Output = $a;
+
+            fn mul(self, rhs: $c) -> Self::Output {
+                <$a as $crate::Measurement>::from_base_units(
+                    $crate::Measurement::as_base_units(&self)
+                        * $crate::Measurement::as_base_units(&rhs),
+                )
+            }
+        }
+
+        impl ::std::ops::Div<$c> for $a {
+            type Output = $b;
+
+            fn div(self, rhs: $c) -> Self::Output {
+                <$b as $crate::Measurement>::from_base_units(
+                    $crate::Measurement::as_base_units(&self)
+                        / $crate::Measurement::as_base_units(&rhs),
+                )
+            }
+        }
+
+        impl ::std::ops::Div<$b> for $a {
+            type Output = $c;
+
+            fn div(self, rhs: $b) -> Self::Output {
+                <$c as $crate::Measurement>::from_base_units(
+                    $crate::Measurement::as_base_units(&self)
+                        / $crate::Measurement::as_base_units(&rhs),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use measurement::round_significant;
+    use test_utils::{assert_almost_eq, assert_almost_eq_delta};
+
+    // Runs against the libm version without the `std` feature, and the std
+    // version with it.
+    #[test]
+    fn round_significant_tiny() {
+        assert_eq!(round_significant(1e-300, 10), 1e-300);
+        assert_almost_eq(round_significant(1.234_567e-300, 3), 1.23e-300);
+        assert_eq!(round_significant(1e-320, 6), 1e-320);
+    }
+
+    #[test]
+    fn round_significant_huge() {
+        assert_almost_eq_delta(round_significant(1e300, 3), 1e300, f64::EPSILON);
+        assert_almost_eq_delta(round_significant(1.234_567e300, 3), 1.23e300, f64::EPSILON);
+        assert_eq!(round_significant(f64::MAX, 1), f64::MAX);
+    }
+}
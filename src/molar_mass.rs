@@ -0,0 +1,188 @@
+//! Types and constants for handling molar mass.
+
+use super::measurement::*;
+
+/// Molar mass of water (H₂O), in g/mol
+pub const WATER_GRAMS_PER_MOLE: f64 = 18.015;
+/// Molar mass of carbon dioxide (CO₂), in g/mol
+pub const CARBON_DIOXIDE_GRAMS_PER_MOLE: f64 = 44.009;
+/// Molar mass of oxygen gas (O₂), in g/mol
+pub const OXYGEN_GRAMS_PER_MOLE: f64 = 31.998;
+/// Molar mass of nitrogen gas (N₂), in g/mol
+pub const NITROGEN_GRAMS_PER_MOLE: f64 = 28.014;
+
+/// Units supported by [`MolarMass`], as (symbol, long name, number of
+/// kilograms per mole in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("g/mol", "grams per mole", 1e-3),
+    ("kg/mol", "kilograms per mole", 1.0),
+];
+
+/// The `MolarMass` struct can be used to deal with the mass of one mole of a
+/// substance in a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{AmountOfSubstance, Mass, MolarMass};
+///
+/// let salt = MolarMass::from_grams_per_mole(58.44);
+/// let amount: AmountOfSubstance = Mass::from_grams(100.0) / salt;
+/// println!("100 g of salt is {} mol.", amount.as_moles());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct MolarMass {
+    kilograms_per_mole: f64,
+}
+
+impl MolarMass {
+    /// Create a new MolarMass from a floating point value in kilograms per mole
+    pub fn from_kilograms_per_mole(kilograms_per_mole: f64) -> Self {
+        MolarMass { kilograms_per_mole }
+    }
+
+    /// Create a new MolarMass from a floating point value in grams per mole
+    pub fn from_grams_per_mole(grams_per_mole: f64) -> Self {
+        Self::from_kilograms_per_mole(grams_per_mole / 1000.0)
+    }
+
+    /// The MolarMass of water (H₂O)
+    pub fn water() -> Self {
+        Self::from_grams_per_mole(WATER_GRAMS_PER_MOLE)
+    }
+
+    /// The MolarMass of carbon dioxide (CO₂)
+    pub fn carbon_dioxide() -> Self {
+        Self::from_grams_per_mole(CARBON_DIOXIDE_GRAMS_PER_MOLE)
+    }
+
+    /// The MolarMass of oxygen gas (O₂)
+    pub fn oxygen() -> Self {
+        Self::from_grams_per_mole(OXYGEN_GRAMS_PER_MOLE)
+    }
+
+    /// The MolarMass of nitrogen gas (N₂)
+    pub fn nitrogen() -> Self {
+        Self::from_grams_per_mole(NITROGEN_GRAMS_PER_MOLE)
+    }
+
+    /// Convert this MolarMass into a floating point value in kilograms per mole
+    pub fn as_kilograms_per_mole(&self) -> f64 {
+        self.kilograms_per_mole
+    }
+
+    /// Convert this MolarMass into a floating point value in grams per mole
+    pub fn as_grams_per_mole(&self) -> f64 {
+        self.kilograms_per_mole * 1000.0
+    }
+}
+
+impl Measurement for MolarMass {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.kilograms_per_mole
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_kilograms_per_mole(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "kg/mol"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [("g/mol", 1e-3), ("kg/mol", 1e0)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "MolarMass"
+    }
+}
+
+implement_debug! { MolarMass, kilograms_per_mole }
+
+implement_measurement! { MolarMass }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn grams_per_mole() {
+        let i1 = MolarMass::from_grams_per_mole(18.0);
+        let r1 = i1.as_kilograms_per_mole();
+        let i2 = MolarMass::from_kilograms_per_mole(0.044);
+        let r2 = i2.as_grams_per_mole();
+        assert_almost_eq(r1, 0.018);
+        assert_almost_eq(r2, 44.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = MolarMass::from_kilograms_per_mole(2.0);
+        let b = MolarMass::from_kilograms_per_mole(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_kilograms_per_mole(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = MolarMass::from_kilograms_per_mole(2.0);
+        let b = MolarMass::from_kilograms_per_mole(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_kilograms_per_mole(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = MolarMass::from_kilograms_per_mole(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_kilograms_per_mole(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = MolarMass::from_kilograms_per_mole(2.0);
+        let b = MolarMass::from_kilograms_per_mole(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_kilograms_per_mole(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = MolarMass::from_kilograms_per_mole(2.0);
+        let b = MolarMass::from_kilograms_per_mole(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = MolarMass::from_kilograms_per_mole(2.0);
+        let b = MolarMass::from_kilograms_per_mole(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = MolarMass::from_kilograms_per_mole(2.0);
+        let b = MolarMass::from_kilograms_per_mole(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
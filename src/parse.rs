@@ -0,0 +1,190 @@
+//! Types and functions shared by the `FromStr` implementations.
+
+use std::error::Error;
+use std::fmt;
+use std::num::ParseFloatError;
+
+/// The error returned when a measurement can't be parsed from a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseMeasurementError {
+    /// The number part of the string isn't a valid number.
+    BadNumber(ParseFloatError),
+    /// The unit part of the string isn't a unit this measurement knows.
+    UnknownUnit(String),
+    /// There is no number in the string, e.g. only a unit was given.
+    Empty,
+}
+
+impl fmt::Display for ParseMeasurementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseMeasurementError::BadNumber(ref e) => write!(f, "invalid number: {}", e),
+            ParseMeasurementError::UnknownUnit(ref unit) => write!(f, "unknown unit: {:?}", unit),
+            ParseMeasurementError::Empty => write!(f, "no number given"),
+        }
+    }
+}
+
+impl Error for ParseMeasurementError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseMeasurementError::BadNumber(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseFloatError> for ParseMeasurementError {
+    fn from(e: ParseFloatError) -> Self {
+        ParseMeasurementError::BadNumber(e)
+    }
+}
+
+/// Parse the number part of a measurement string.
+pub(crate) fn parse_number(val: &str) -> Result<f64, ParseMeasurementError> {
+    if val.trim().is_empty() {
+        return Err(ParseMeasurementError::Empty);
+    }
+    Ok(val.parse::<f64>()?)
+}
+
+/// Split a measurement string into its number and unit, e.g. " 12.5 kg " into
+/// ("12.5", "kg"). Whitespace (including non-breaking and other unicode
+/// spaces) around the number and unit is ignored, and any whitespace within
+/// the unit is collapsed to a single space, so "fl\u{00A0}\u{00A0}oz" is
+/// "fl oz". Either part may be empty.
+///
+/// The number may have a sign and an exponent, but the exponent is only taken
+/// if digits follow it, so "10eV" is 10 eV.
+pub(crate) fn split_number_unit(val: &str) -> (&str, String) {
+    let val = val.trim();
+    let bytes = val.as_bytes();
+    let digits_from = |mut i: usize| {
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+            i += 1;
+        }
+        i
+    };
+    let mut end = 0;
+    if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+        end += 1;
+    }
+    end = digits_from(end);
+    if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+        let mut exponent = end + 1;
+        if exponent < bytes.len() && (bytes[exponent] == b'+' || bytes[exponent] == b'-') {
+            exponent += 1;
+        }
+        if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+            end = digits_from(exponent);
+        }
+    }
+    // Everything consumed is ASCII, so `end` is on a character boundary.
+    let unit = val[end..].split_whitespace().collect::<Vec<_>>().join(" ");
+    (&val[..end], unit)
+}
+
+/// Remove a leading degree sign ("°", or the look-alikes "º" and "˚") or
+/// "deg", "degree" or "degrees" from a unit, e.g. "° C" or "deg C" gives "C".
+pub(crate) fn strip_degree(unit: &str) -> &str {
+    let lower = unit.to_lowercase();
+    for prefix in &[
+        "\u{00B0}", "\u{00BA}", "\u{02DA}", "degrees", "degree", "deg",
+    ] {
+        if lower.starts_with(prefix) {
+            // The prefixes are the same length in either case.
+            return unit[prefix.len()..].trim_start();
+        }
+    }
+    unit
+}
+
+/// The error for a unit which isn't recognised.
+pub(crate) fn unknown_unit(unit: &str) -> ParseMeasurementError {
+    ParseMeasurementError::UnknownUnit(unit.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+    use {Angle, Mass, Power, Temperature, Volume};
+
+    #[test]
+    fn unknown_unit() {
+        match Mass::from_str("5 furlongs_per_fortnight") {
+            Err(ParseMeasurementError::UnknownUnit(_)) => {}
+            other => panic!("expected UnknownUnit, got {:?}", other),
+        }
+        assert_eq!(
+            Power::from_str("5 Xw"),
+            Err(ParseMeasurementError::UnknownUnit("Xw".to_string()))
+        );
+    }
+
+    #[test]
+    fn bad_number() {
+        match Angle::from_str("1.2.3 deg") {
+            Err(ParseMeasurementError::BadNumber(_)) => {}
+            other => panic!("expected BadNumber, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Power::from_str("kW"), Err(ParseMeasurementError::Empty));
+        assert_eq!(
+            Temperature::from_str("   "),
+            Err(ParseMeasurementError::Empty)
+        );
+    }
+
+    #[test]
+    fn plain_number() {
+        let v = Volume::from_str("12.5").unwrap();
+        assert_eq!(v.as_liters(), 12.5);
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(split_number_unit(" 12.5 kg "), ("12.5", "kg".to_string()));
+        assert_eq!(split_number_unit("-40C"), ("-40", "C".to_string()));
+        assert_eq!(split_number_unit("1e3 W"), ("1e3", "W".to_string()));
+        assert_eq!(split_number_unit("10eV"), ("10", "eV".to_string()));
+        assert_eq!(split_number_unit("10"), ("10", String::new()));
+        assert_eq!(split_number_unit("kg"), ("", "kg".to_string()));
+        assert_eq!(
+            split_number_unit("\u{2009}10\u{00A0}US \u{00A0}fl\u{00A0}oz\u{00A0}"),
+            ("10", "US fl oz".to_string())
+        );
+    }
+
+    #[test]
+    fn degree() {
+        assert_eq!(strip_degree("\u{00B0}C"), "C");
+        assert_eq!(strip_degree("deg F"), "F");
+        assert_eq!(strip_degree("Degrees K"), "K");
+        assert_eq!(strip_degree("\u{00BA} c"), "c");
+        assert_eq!(strip_degree("rad"), "rad");
+    }
+
+    #[test]
+    fn odd_whitespace() {
+        let m = Mass::from_str("123\u{00A0}kg").unwrap();
+        assert_eq!(m.as_kilograms(), 123.0);
+        let t = Temperature::from_str("\u{2009}100\u{00A0}\u{00B0}C\u{00A0}").unwrap();
+        assert_eq!(t.as_celsius(), 100.0);
+        let t = Temperature::from_str("-40 deg F").unwrap();
+        assert!((t.as_celsius() + 40.0).abs() < 1e-9);
+        let v = Volume::from_str("10\u{00A0}US\u{00A0}fl  oz").unwrap();
+        assert_eq!(v, Volume::from_fluid_ounces(10.0));
+        let a = Angle::from_str("90\u{202F}\u{00BA}").unwrap();
+        assert_eq!(a.as_degrees(), 90.0);
+    }
+
+    #[test]
+    fn display() {
+        let e = ParseMeasurementError::UnknownUnit("xyz".to_string());
+        assert_eq!(e.to_string(), "unknown unit: \"xyz\"");
+    }
+}
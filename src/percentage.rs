@@ -0,0 +1,225 @@
+//! Types and constants for handling percentages.
+
+use super::measurement::*;
+use humidity::Humidity;
+use ratio::Ratio;
+
+/// The `Percentage` struct can be used to deal with percentages (efficiency,
+/// duty cycle, grade, etc.) in a common way.
+///
+/// Any measurement can be multiplied by a `Percentage` to scale it.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Percentage, Power};
+///
+/// let max_power = Power::from_watts(200.0);
+/// let duty = Percentage::from_percent(50.0);
+/// println!("At {} duty, the heater draws {}.", duty, duty * max_power);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Percentage {
+    percent: f64,
+}
+
+impl Percentage {
+    /// Create a new Percentage from a floating point value percentage (i.e. 0.0% to 100.0%)
+    pub fn from_percent(percent: f64) -> Self {
+        Percentage { percent }
+    }
+
+    /// Create a new Percentage from a floating point value ratio (i.e. 0.0 to 1.0)
+    pub fn from_ratio(ratio: f64) -> Self {
+        Self::from_percent(ratio * 100.0)
+    }
+
+    /// Convert this Percentage to a value expressed as a percentage (i.e. 0.0% to 100.0%)
+    pub fn as_percent(&self) -> f64 {
+        self.percent
+    }
+
+    /// Convert this Percentage to a value expressed as a ratio (i.e. 0.0 to 1.0)
+    pub fn as_ratio(&self) -> f64 {
+        self.percent / 100.0
+    }
+}
+
+impl Measurement for Percentage {
+    fn as_base_units(&self) -> f64 {
+        self.percent
+    }
+
+    fn from_base_units(percent: f64) -> Self {
+        Self::from_percent(percent)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "%"
+    }
+
+    fn quantity_name() -> &'static str {
+        "Percentage"
+    }
+}
+
+impl ::std::convert::From<Humidity> for Percentage {
+    fn from(h: Humidity) -> Percentage {
+        Percentage::from_percent(h.as_percent())
+    }
+}
+
+impl ::std::convert::From<Ratio> for Percentage {
+    fn from(r: Ratio) -> Percentage {
+        Percentage::from_ratio(r.as_ratio())
+    }
+}
+
+impl ::std::convert::From<Percentage> for Ratio {
+    fn from(p: Percentage) -> Ratio {
+        Ratio::from_ratio(p.as_ratio())
+    }
+}
+
+// `Percentage` can't use `implement_measurement!`, as that would implement
+// `Mul<Percentage>` for `Percentage` twice.
+
+impl ::std::ops::Add for Percentage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_percent(self.percent + rhs.percent)
+    }
+}
+
+impl ::std::ops::Sub for Percentage {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_percent(self.percent - rhs.percent)
+    }
+}
+
+// Taking a percentage of a percentage (e.g. 50% of 50% is 25%).
+impl ::std::ops::Mul<Percentage> for Percentage {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_percent(self.percent * rhs.as_ratio())
+    }
+}
+
+impl ::std::ops::Mul<f64> for Percentage {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_percent(self.percent * rhs)
+    }
+}
+
+impl ::std::ops::Mul<Percentage> for f64 {
+    type Output = Percentage;
+
+    fn mul(self, rhs: Percentage) -> Percentage {
+        rhs * self
+    }
+}
+
+impl ::std::ops::Div<f64> for Percentage {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::from_percent(self.percent / rhs)
+    }
+}
+
+impl ::std::cmp::Eq for Percentage {}
+impl ::std::cmp::PartialEq for Percentage {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_base_units() == other.as_base_units()
+    }
+}
+
+impl ::std::cmp::PartialOrd for Percentage {
+    fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        self.as_base_units().partial_cmp(&other.as_base_units())
+    }
+}
+
+implement_debug!(Percentage, percent);
+implement_display!(Percentage);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use power::Power;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn percent() {
+        let p = Percentage::from_percent(50.0);
+        assert_almost_eq(p.as_percent(), 50.0);
+        assert_almost_eq(p.as_ratio(), 0.5);
+    }
+
+    #[test]
+    fn ratio() {
+        let p = Percentage::from_ratio(0.1);
+        assert_almost_eq(p.as_percent(), 10.0);
+        assert_almost_eq(p.as_ratio(), 0.1);
+    }
+
+    #[test]
+    fn from_humidity() {
+        let p = Percentage::from(Humidity::from_percent(85.0));
+        assert_almost_eq(p.as_percent(), 85.0);
+    }
+
+    #[test]
+    fn to_and_from_ratio() {
+        let p = Percentage::from(Ratio::from_ratio(0.25));
+        assert_almost_eq(p.as_percent(), 25.0);
+        let r = Ratio::from(p);
+        assert_almost_eq(r.as_ratio(), 0.25);
+    }
+
+    #[test]
+    fn scale_power() {
+        let p = Percentage::from_percent(50.0);
+        let w = Power::from_watts(200.0);
+        let a = w * p;
+        let b = p * w;
+        assert_almost_eq(a.as_watts(), 100.0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn percent_of_percent() {
+        let a = Percentage::from_percent(50.0);
+        let b = Percentage::from_percent(50.0);
+        assert_almost_eq((a * b).as_percent(), 25.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Percentage::from_percent(20.0);
+        let b = Percentage::from_percent(30.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_percent(), 50.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Percentage::from_percent(19.0);
+        let b = Percentage::from_percent(20.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
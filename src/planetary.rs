@@ -0,0 +1,164 @@
+//! Surface conditions on planets and moons, for physics helpers which would
+//! otherwise assume the Earth.
+
+use acceleration::Acceleration;
+use constants::{STANDARD_ATMOSPHERE_PASCALS, STANDARD_GRAVITY_METERS_PER_SECOND_PER_SECOND};
+use length::Length;
+use pressure::Pressure;
+
+/// The surface gravity, atmospheric pressure and atmospheric scale height of
+/// a planet or moon, for use with helpers such as `Length::free_fall_time_on`
+/// and `Mass::weight_on`.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Length, PlanetaryContext};
+///
+/// let moon = PlanetaryContext::moon();
+/// let drop = Length::from_meters(10.0);
+/// println!("A 10 m drop on the Moon takes {:?}.", drop.free_fall_time_on(&moon));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PlanetaryContext {
+    gravity: Acceleration,
+    surface_pressure: Pressure,
+    scale_height: Length,
+}
+
+impl PlanetaryContext {
+    /// Create a new PlanetaryContext from the surface gravity, the mean
+    /// atmospheric pressure at the surface and the atmospheric scale height
+    /// (the height over which the pressure falls by a factor of e)
+    pub fn new(gravity: Acceleration, surface_pressure: Pressure, scale_height: Length) -> Self {
+        PlanetaryContext {
+            gravity,
+            surface_pressure,
+            scale_height,
+        }
+    }
+
+    /// The Earth, with standard gravity and standard atmospheric pressure
+    pub fn earth() -> Self {
+        Self::new(
+            Acceleration::from_meters_per_second_per_second(
+                STANDARD_GRAVITY_METERS_PER_SECOND_PER_SECOND,
+            ),
+            Pressure::from_pascals(STANDARD_ATMOSPHERE_PASCALS),
+            Length::from_kilometers(8.5),
+        )
+    }
+
+    /// Mars, with its mean surface pressure
+    pub fn mars() -> Self {
+        Self::new(
+            Acceleration::from_meters_per_second_per_second(3.721),
+            Pressure::from_pascals(610.0),
+            Length::from_kilometers(11.1),
+        )
+    }
+
+    /// The Moon, which has no significant atmosphere
+    pub fn moon() -> Self {
+        Self::new(
+            Acceleration::from_meters_per_second_per_second(1.625),
+            Pressure::from_pascals(0.0),
+            Length::from_meters(0.0),
+        )
+    }
+
+    /// The acceleration due to gravity at the surface
+    pub fn gravity(&self) -> Acceleration {
+        self.gravity
+    }
+
+    /// The mean atmospheric pressure at the surface
+    pub fn surface_pressure(&self) -> Pressure {
+        self.surface_pressure
+    }
+
+    /// The atmospheric scale height
+    pub fn scale_height(&self) -> Length {
+        self.scale_height
+    }
+
+    /// Calculate the atmospheric pressure at the given altitude above the
+    /// surface, assuming an isothermal atmosphere (p₀ × e^(−h / H)). A body
+    /// without an atmosphere (a scale height of zero) has zero pressure at
+    /// every altitude.
+    #[cfg(feature = "std")]
+    pub fn pressure_at_altitude(&self, altitude: Length) -> Pressure {
+        let scale_height = self.scale_height.as_meters();
+        if scale_height <= 0.0 {
+            return Pressure::from_pascals(0.0);
+        }
+        self.surface_pressure * (-altitude.as_meters() / scale_height).exp()
+    }
+
+    /// Calculate the atmospheric pressure at the given altitude above the
+    /// surface, assuming an isothermal atmosphere (p₀ × e^(−h / H)). A body
+    /// without an atmosphere (a scale height of zero) has zero pressure at
+    /// every altitude.
+    #[cfg(not(feature = "std"))]
+    pub fn pressure_at_altitude(&self, altitude: Length) -> Pressure {
+        let scale_height = self.scale_height.as_meters();
+        if scale_height <= 0.0 {
+            return Pressure::from_pascals(0.0);
+        }
+        self.surface_pressure * libm::exp(-altitude.as_meters() / scale_height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use mass::Mass;
+    use measurement::Measurement;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn free_fall_on_the_moon() {
+        let moon = PlanetaryContext::moon();
+        let t = Length::from_meters(10.0).free_fall_time_on(&moon);
+        assert_almost_eq(t.as_base_units(), 3.508_232);
+        let v = Length::from_meters(10.0).free_fall_velocity_on(&moon);
+        assert_almost_eq(v.as_meters_per_second(), 5.700_877);
+        let earth = PlanetaryContext::earth();
+        let t = Length::from_meters(10.0).free_fall_time(earth.gravity());
+        assert_almost_eq(t.as_base_units(), 1.428_087);
+    }
+
+    #[test]
+    fn pendulum_on_mars() {
+        let mars = PlanetaryContext::mars();
+        let t = Length::from_meters(1.0).pendulum_period_on(&mars);
+        assert_almost_eq(t.as_base_units(), 3.257_242);
+    }
+
+    #[test]
+    fn weight_on() {
+        let m = Mass::from_kilograms(100.0);
+        assert_almost_eq(m.weight_on(&PlanetaryContext::mars()).as_newtons(), 372.1);
+        assert_eq!(m.weight_on(&PlanetaryContext::earth()), m.weight_on_earth());
+    }
+
+    #[test]
+    fn pressure_at_altitude() {
+        let earth = PlanetaryContext::earth();
+        let p = earth.pressure_at_altitude(Length::from_kilometers(8.5));
+        assert_almost_eq(p.as_pascals(), 101_325.0 / ::std::f64::consts::E);
+        let p = earth.pressure_at_altitude(Length::from_meters(0.0));
+        assert_almost_eq(p.as_pascals(), 101_325.0);
+        let moon = PlanetaryContext::moon();
+        let p = moon.pressure_at_altitude(Length::from_meters(100.0));
+        assert_eq!(p.as_pascals(), 0.0);
+        let flat = PlanetaryContext::new(
+            Acceleration::from_meters_per_second_per_second(1.0),
+            Pressure::from_pascals(500.0),
+            Length::from_meters(0.0),
+        );
+        let p = flat.pressure_at_altitude(Length::from_meters(100.0));
+        assert_eq!(p.as_pascals(), 0.0);
+    }
+}
@@ -1,6 +1,12 @@
 //! Types and constants for handling power.
 
 use super::measurement::*;
+use energy::Energy;
+#[cfg(feature = "from_str")]
+use parse::{parse_number, split_number_unit, unknown_unit, ParseMeasurementError};
+#[cfg(feature = "from_str")]
+use std::str::FromStr;
+use time;
 
 /// Number of horsepower in a watt
 pub const WATT_HORSEPOWER_FACTOR: f64 = 1.0 / 745.6998715822702;
@@ -15,6 +21,18 @@ pub const WATT_MICROWATT_FACTOR: f64 = 1e6;
 /// Number of pferdstarken (PS) in a W
 pub const WATT_PS_FACTOR: f64 = 1.0 / 735.499;
 
+/// Units supported by [`Power`], as (symbol, long name, number of watts
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("\u{00B5}W", "microwatts", 1.0 / WATT_MICROWATT_FACTOR),
+    ("mW", "milliwatts", 1.0 / WATT_MILLIWATT_FACTOR),
+    ("W", "watts", 1.0),
+    ("kW", "kilowatts", 1.0 / WATT_KILOWATT_FACTOR),
+    ("hp", "horsepower", 1.0 / WATT_HORSEPOWER_FACTOR),
+    ("PS", "metric horsepower", 1.0 / WATT_PS_FACTOR),
+    ("BTU/min", "BTU per minute", 1.0 / WATT_BTU_MIN_FACTOR),
+];
+
 /// The `Power` struct can be used to deal with energies in a common way.
 /// Common metric and imperial units are supported.
 ///
@@ -28,7 +46,8 @@ pub const WATT_PS_FACTOR: f64 = 1.0 / 735.499;
 /// println!("A 100.0 hp car produces {} kW", k_w);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Power {
     watts: f64,
 }
@@ -113,9 +132,21 @@ impl Power {
     pub fn as_microwatts(&self) -> f64 {
         self.watts * WATT_MICROWATT_FACTOR
     }
+
+    /// Create a new Power from the average rate at which the given Energy
+    /// was used or delivered over the given Duration (E / t). This is the
+    /// same as `energy / over`.
+    ///
+    /// A zero Duration deliberately gives an infinite Power (or NaN, if the
+    /// Energy is also zero), as IEEE 754 division does, rather than panicking.
+    pub fn average(energy: Energy, over: time::Duration) -> Self {
+        Self::from_watts(energy.as_joules() / over.as_base_units())
+    }
 }
 
 impl Measurement for Power {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.watts
     }
@@ -146,8 +177,44 @@ impl Measurement for Power {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Power"
+    }
 }
 
+#[cfg(feature = "from_str")]
+impl FromStr for Power {
+    type Err = ParseMeasurementError;
+
+    /// Create a new Power from a string
+    /// Plain numbers in string are considered to be Watts. Units are case
+    /// sensitive, to tell MW and mW apart.
+    fn from_str(val: &str) -> Result<Self, Self::Err> {
+        if val.is_empty() {
+            return Ok(Power::from_watts(0.0));
+        }
+
+        let (number, unit) = split_number_unit(val);
+        Ok(match unit.as_str() {
+            "" => Power::from_watts(parse_number(number)?),
+            "\u{00B5}W" | "uW" => Power::from_microwatts(parse_number(number)?),
+            "mW" => Power::from_milliwatts(parse_number(number)?),
+            "W" => Power::from_watts(parse_number(number)?),
+            "kW" => Power::from_kilowatts(parse_number(number)?),
+            "MW" => Power::from_kilowatts(parse_number(number)? * 1e3),
+            "GW" => Power::from_kilowatts(parse_number(number)? * 1e6),
+            "hp" => Power::from_horsepower(parse_number(number)?),
+            "PS" => Power::from_ps(parse_number(number)?),
+            "BTU/min" | "Btu/min" => Power::from_btu_per_minute(parse_number(number)?),
+            "BTU/h" | "Btu/h" => Power::from_btu_per_minute(parse_number(number)? / 60.0),
+            _ => return Err(unknown_unit(&unit)),
+        })
+    }
+}
+
+implement_debug! { Power, watts }
+
 implement_measurement! { Power }
 
 #[cfg(test)]
@@ -217,6 +284,79 @@ mod test {
         assert_almost_eq(r2, 100_000.0);
     }
 
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn watts_str() {
+        let t = Power::from_str("500 W");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_watts();
+        assert_almost_eq(o, 500.0);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn megawatts_str() {
+        let t = Power::from_str("2MW");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_kilowatts();
+        assert_almost_eq(o, 2000.0);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn milliwatts_str() {
+        let t = Power::from_str("2 mW");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_milliwatts();
+        assert_almost_eq(o, 2.0);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn horsepower_str() {
+        let t = Power::from_str("100 hp");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_watts();
+        assert_almost_eq(o, 74569.98715822702);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn btu_per_hour_str() {
+        let t = Power::from_str("60 BTU/h");
+        assert!(t.is_ok());
+
+        let o = t.unwrap().as_btu_per_minute();
+        assert_almost_eq(o, 1.0);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn invalid_str() {
+        let t = Power::from_str("abcd");
+        assert!(t.is_err());
+    }
+
+    #[test]
+    fn scaled_by_percent() {
+        let max = Power::from_watts(100.0);
+        assert_almost_eq(max.scaled_by_percent(75.0).as_watts(), 75.0);
+        assert_almost_eq(max.scaled_by_percent(150.0).as_watts(), 150.0);
+        assert_eq!(max.scaled_by_percent(0.0).as_watts(), 0.0);
+    }
+
+    #[test]
+    fn average() {
+        let p = Power::average(Energy::from_joules(3600.0), time::Duration::from_secs(60));
+        assert_almost_eq(p.as_watts(), 60.0);
+        let p = Power::average(Energy::from_joules(1.0), time::Duration::from_secs(0));
+        assert!(p.as_watts().is_infinite());
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -2,3 +2,4 @@
 //! You should wildcard import this file.
 
 pub use super::Measurement;
+pub use super::MeasurementIterator;
@@ -1,6 +1,8 @@
 //! Types and constants for handling pressure.
 
 use super::measurement::*;
+use area::Area;
+use force::Force;
 
 /// Number of Pascals in an atomosphere
 pub const PASCAL_ATMOSPHERE_FACTOR: f64 = 101_325.0;
@@ -14,6 +16,20 @@ pub const PASCAL_MILLIBAR_FACTOR: f64 = 100.0;
 pub const PASCAL_BAR_FACTOR: f64 = 100_000.0;
 /// Number of Pascals in a PSI
 pub const PASCAL_PSI_FACTOR: f64 = 6894.76;
+/// Reference pressure for sound pressure level (0 dB SPL), in Pascals
+pub const SPL_REFERENCE_PASCALS: f64 = 20e-6;
+
+/// Units supported by [`Pressure`], as (symbol, long name, number of pascals
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("Pa", "pascals", 1.0),
+    ("hPa", "hectopascals", PASCAL_HECTOPASCAL_FACTOR),
+    ("mbar", "millibars", PASCAL_MILLIBAR_FACTOR),
+    ("kPa", "kilopascals", PASCAL_KILOPASCAL_FACTOR),
+    ("psi", "pounds per square inch", PASCAL_PSI_FACTOR),
+    ("bar", "bars", PASCAL_BAR_FACTOR),
+    ("atm", "atmospheres", PASCAL_ATMOSPHERE_FACTOR),
+];
 
 /// The `Pressure` struct can be used to deal with presssures in a common way.
 /// Common metric and imperial units are supported.
@@ -28,7 +44,8 @@ pub const PASCAL_PSI_FACTOR: f64 = 6894.76;
 /// println!("Atmospheric pressure is {} mbar.", mbar);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Pressure {
     pascals: f64,
 }
@@ -69,6 +86,20 @@ impl Pressure {
         Self::from_pascals(atmospheres * PASCAL_ATMOSPHERE_FACTOR)
     }
 
+    /// Create new Pressure from a sound pressure level in decibels (dB SPL),
+    /// relative to 20 µPa.
+    #[cfg(feature = "std")]
+    pub fn from_db_spl(db_spl: f64) -> Pressure {
+        Self::from_pascals(SPL_REFERENCE_PASCALS * 10f64.powf(db_spl / 20.0))
+    }
+
+    /// Create new Pressure from a sound pressure level in decibels (dB SPL),
+    /// relative to 20 µPa.
+    #[cfg(not(feature = "std"))]
+    pub fn from_db_spl(db_spl: f64) -> Pressure {
+        Self::from_pascals(SPL_REFERENCE_PASCALS * libm::pow(10.0, db_spl / 20.0))
+    }
+
     /// Convert this Pressure into a floating point value in Pascals
     pub fn as_pascals(&self) -> f64 {
         self.pascals
@@ -103,9 +134,46 @@ impl Pressure {
     pub fn as_atmospheres(&self) -> f64 {
         self.pascals / PASCAL_ATMOSPHERE_FACTOR
     }
+
+    /// Treating this Pressure as a (tensile or compressive) stress, calculate
+    /// the resulting strain in a material with the given Young's modulus:
+    /// ε = σ / E. The strain is dimensionless (e.g. 0.001 is 0.1% elongation).
+    pub fn strain_under_stress(&self, youngs_modulus: Pressure) -> f64 {
+        self.pascals / youngs_modulus.pascals
+    }
+
+    /// Calculate the stress needed to cause the given (dimensionless) strain
+    /// in a material with the given Young's modulus: σ = ε × E.
+    pub fn stress_from_strain(strain: f64, youngs_modulus: Pressure) -> Pressure {
+        Self::from_pascals(strain * youngs_modulus.pascals)
+    }
+
+    /// Convert this (RMS sound) Pressure into a sound pressure level in
+    /// decibels (dB SPL), relative to 20 µPa. A negative pressure is taken
+    /// by its magnitude, and zero pressure gives negative infinity.
+    #[cfg(feature = "std")]
+    pub fn as_db_spl(&self) -> f64 {
+        20.0 * (self.pascals.abs() / SPL_REFERENCE_PASCALS).log10()
+    }
+
+    /// Convert this (RMS sound) Pressure into a sound pressure level in
+    /// decibels (dB SPL), relative to 20 µPa. A negative pressure is taken
+    /// by its magnitude, and zero pressure gives negative infinity.
+    #[cfg(not(feature = "std"))]
+    pub fn as_db_spl(&self) -> f64 {
+        20.0 * libm::log10(libm::fabs(self.pascals) / SPL_REFERENCE_PASCALS)
+    }
+
+    /// Calculate the Force this Pressure exerts on the given Area (F = P × A).
+    /// This is the same as `pressure * area`.
+    pub fn force_on(&self, area: Area) -> Force {
+        *self * area
+    }
 }
 
 impl Measurement for Pressure {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.pascals
     }
@@ -130,13 +198,38 @@ impl Measurement for Pressure {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Pressure"
+    }
 }
 
+implement_debug! { Pressure, pascals }
+
 implement_measurement! { Pressure }
 
+/// Combine two sound pressure levels, in decibels (dB SPL), from
+/// uncorrelated sources. Decibels add logarithmically, not arithmetically:
+/// two equal sources are about 3 dB louder than one alone, so 80 dB and
+/// 80 dB make 83 dB, not 160 dB.
+#[cfg(feature = "std")]
+pub fn db_spl_add(a: f64, b: f64) -> f64 {
+    10.0 * (10f64.powf(a / 10.0) + 10f64.powf(b / 10.0)).log10()
+}
+
+/// Combine two sound pressure levels, in decibels (dB SPL), from
+/// uncorrelated sources. Decibels add logarithmically, not arithmetically:
+/// two equal sources are about 3 dB louder than one alone, so 80 dB and
+/// 80 dB make 83 dB, not 160 dB.
+#[cfg(not(feature = "std"))]
+pub fn db_spl_add(a: f64, b: f64) -> f64 {
+    10.0 * libm::log10(libm::pow(10.0, a / 10.0) + libm::pow(10.0, b / 10.0))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use range::Span;
     use test_utils::assert_almost_eq;
 
     #[test]
@@ -201,6 +294,72 @@ mod test {
         assert_almost_eq(o, 689476.9760513823);
     }
 
+    #[test]
+    fn db_spl() {
+        let i1 = Pressure::from_pascals(20e-6);
+        let r1 = i1.as_db_spl();
+        let i2 = Pressure::from_pascals(1.0);
+        let r2 = i2.as_db_spl();
+        assert_eq!(r1, 0.0);
+        assert_almost_eq(r2, 93.979400);
+    }
+
+    #[test]
+    fn from_db_spl() {
+        let i1 = Pressure::from_db_spl(0.0);
+        let r1 = i1.as_pascals();
+        let i2 = Pressure::from_db_spl(94.0);
+        let r2 = i2.as_pascals();
+        assert_almost_eq(r1, 20e-6);
+        assert_almost_eq(r2, 1.002374);
+    }
+
+    #[test]
+    fn db_spl_non_positive() {
+        assert_eq!(Pressure::from_pascals(0.0).as_db_spl(), f64::NEG_INFINITY);
+        assert_almost_eq(Pressure::from_pascals(-1.0).as_db_spl(), 93.979400);
+    }
+
+    #[test]
+    fn db_spl_add() {
+        assert_almost_eq(super::db_spl_add(80.0, 80.0), 83.010300);
+        assert_almost_eq(super::db_spl_add(90.0, 70.0), 90.043214);
+        assert_almost_eq(super::db_spl_add(60.0, f64::NEG_INFINITY), 60.0);
+    }
+
+    #[test]
+    fn strain_under_stress() {
+        let steel = Pressure::from_kilopascals(200e6);
+        let stress = Pressure::from_kilopascals(100e3);
+        assert_almost_eq(stress.strain_under_stress(steel), 0.0005);
+    }
+
+    #[test]
+    fn stress_from_strain() {
+        let steel = Pressure::from_kilopascals(200e6);
+        let stress = Pressure::stress_from_strain(0.001, steel);
+        assert_almost_eq(stress.as_kilopascals(), 200e3);
+        assert_almost_eq(stress.strain_under_stress(steel), 0.001);
+    }
+
+    #[test]
+    fn force_on() {
+        let p = Pressure::from_kilopascals(100.0);
+        let f = p.force_on(Area::from_square_meters(2.0));
+        assert_almost_eq(f.as_newtons(), 200_000.0);
+    }
+
+    #[test]
+    fn clamp_to() {
+        let operating = Span::new(Pressure::from_bars(1.0), Pressure::from_bars(6.0));
+        let high = Pressure::from_bars(7.5).clamp_to(&operating);
+        assert_almost_eq(high.as_bars(), 6.0);
+        let low = Pressure::from_kilopascals(20.0).clamp_to(&operating);
+        assert_almost_eq(low.as_bars(), 1.0);
+        let ok = Pressure::from_bars(3.0).clamp_to(&operating);
+        assert_almost_eq(ok.as_bars(), 3.0);
+    }
+
     // Traits
     #[test]
     fn add() {
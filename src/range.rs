@@ -0,0 +1,218 @@
+//! Functions for generating sequences of measurements, such as axis ticks or
+//! sweep values, and the `Span` type for an inclusive range of measurements.
+
+use super::measurement::*;
+
+/// Returns an iterator over `n` evenly spaced measurements from `start` to
+/// `end` inclusive, interpolated in base units.
+///
+/// If `n` is one, only `start` is produced. If `n` is zero, nothing is.
+///
+/// # Example
+///
+/// ```
+/// use measurements::range::linspace;
+/// use measurements::Temperature;
+///
+/// let start = Temperature::from_celsius(0.0);
+/// let end = Temperature::from_celsius(100.0);
+/// for t in linspace(start, end, 5) {
+///     println!("{:.0} °C", t.as_celsius());
+/// }
+/// ```
+pub fn linspace<M: Measurement>(start: M, end: M, n: usize) -> impl Iterator<Item = M> {
+    let start = start.as_base_units();
+    let end = end.as_base_units();
+    (0..n).map(move |i| {
+        if i == 0 {
+            M::from_base_units(start)
+        } else {
+            let t = i as f64 / (n - 1) as f64;
+            M::from_base_units(start * (1.0 - t) + end * t)
+        }
+    })
+}
+
+/// Returns an iterator over the measurements `start`, `start + step`,
+/// `start + 2 * step`, etc. up to but not including `end`, calculated in
+/// base units.
+///
+/// A negative `step` counts down to `end` instead. A step of zero (or one
+/// pointing away from `end`) produces nothing.
+///
+/// # Example
+///
+/// ```
+/// use measurements::range::range_step;
+/// use measurements::Length;
+///
+/// let start = Length::from_meters(0.0);
+/// let end = Length::from_meters(1.0);
+/// for l in range_step(start, end, Length::from_centimeters(10.0)) {
+///     println!("Take a sample at {}", l);
+/// }
+/// ```
+pub fn range_step<M: Measurement>(start: M, end: M, step: M) -> impl Iterator<Item = M> {
+    let start = start.as_base_units();
+    let end = end.as_base_units();
+    let step = step.as_base_units();
+    (0usize..)
+        .map(move |i| start + i as f64 * step)
+        .take_while(move |&value| {
+            if step > 0.0 {
+                value < end
+            } else if step < 0.0 {
+                value > end
+            } else {
+                false
+            }
+        })
+        .map(M::from_base_units)
+}
+
+/// An inclusive range of measurements, such as the operating range of a
+/// sensor. The endpoints are ordered by their value in base units, so the
+/// minimum is never above the maximum.
+///
+/// # Example
+///
+/// ```
+/// use measurements::range::Span;
+/// use measurements::{Measurement, Pressure};
+///
+/// let operating = Span::new(Pressure::from_bars(1.0), Pressure::from_bars(6.0));
+/// let reading = Pressure::from_bars(7.5);
+/// if !operating.contains(&reading) {
+///     println!("Limited to {}.", reading.clamp_to(&operating));
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Span<M> {
+    pub(crate) min: M,
+    pub(crate) max: M,
+}
+
+impl<M: Measurement + Copy> Span<M> {
+    /// Create a new Span between two measurements, in either order
+    pub fn new(a: M, b: M) -> Self {
+        if b.as_base_units() < a.as_base_units() {
+            Span { min: b, max: a }
+        } else {
+            Span { min: a, max: b }
+        }
+    }
+
+    /// The lowest measurement in this Span
+    pub fn min(&self) -> M {
+        self.min
+    }
+
+    /// The highest measurement in this Span
+    pub fn max(&self) -> M {
+        self.max
+    }
+
+    /// Whether the given measurement lies within this Span, including its
+    /// endpoints
+    pub fn contains(&self, value: &M) -> bool {
+        let value = value.as_base_units();
+        self.min.as_base_units() <= value && value <= self.max.as_base_units()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use length::Length;
+    use temperature::Temperature;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn span() {
+        let span = Span::new(Length::from_meters(2.0), Length::from_meters(1.0));
+        assert_almost_eq(span.min().as_meters(), 1.0);
+        assert_almost_eq(span.max().as_meters(), 2.0);
+        assert!(span.contains(&Length::from_meters(1.0)));
+        assert!(span.contains(&Length::from_centimeters(150.0)));
+        assert!(!span.contains(&Length::from_meters(2.5)));
+    }
+
+    #[test]
+    fn linspace_temperatures() {
+        let start = Temperature::from_celsius(0.0);
+        let end = Temperature::from_celsius(100.0);
+        let mut values = linspace(start, end, 5);
+        assert_eq!(values.next().unwrap().as_celsius(), 0.0);
+        assert_almost_eq(values.next().unwrap().as_celsius(), 25.0);
+        assert_almost_eq(values.next().unwrap().as_celsius(), 50.0);
+        assert_almost_eq(values.next().unwrap().as_celsius(), 75.0);
+        assert_almost_eq(values.next().unwrap().as_celsius(), 100.0);
+        assert!(values.next().is_none());
+    }
+
+    #[test]
+    fn linspace_descending() {
+        let start = Temperature::from_kelvin(300.0);
+        let end = Temperature::from_kelvin(200.0);
+        let mut values = linspace(start, end, 3);
+        assert_almost_eq(values.next().unwrap().as_kelvin(), 300.0);
+        assert_almost_eq(values.next().unwrap().as_kelvin(), 250.0);
+        assert_almost_eq(values.next().unwrap().as_kelvin(), 200.0);
+        assert!(values.next().is_none());
+    }
+
+    #[test]
+    fn linspace_one() {
+        let start = Temperature::from_celsius(10.0);
+        let end = Temperature::from_celsius(20.0);
+        let mut values = linspace(start, end, 1);
+        assert_almost_eq(values.next().unwrap().as_celsius(), 10.0);
+        assert!(values.next().is_none());
+    }
+
+    #[test]
+    fn linspace_zero() {
+        let start = Temperature::from_celsius(10.0);
+        let end = Temperature::from_celsius(20.0);
+        assert!(linspace(start, end, 0).next().is_none());
+    }
+
+    #[test]
+    fn range_step_lengths() {
+        let start = Length::from_meters(0.0);
+        let end = Length::from_meters(1.0);
+        let step = Length::from_centimeters(25.0);
+        let mut values = range_step(start, end, step);
+        assert_eq!(values.next().unwrap().as_meters(), 0.0);
+        assert_almost_eq(values.next().unwrap().as_meters(), 0.25);
+        assert_almost_eq(values.next().unwrap().as_meters(), 0.5);
+        assert_almost_eq(values.next().unwrap().as_meters(), 0.75);
+        assert!(values.next().is_none());
+    }
+
+    #[test]
+    fn range_step_negative() {
+        let start = Length::from_meters(1.0);
+        let end = Length::from_meters(0.0);
+        let step = Length::from_centimeters(-50.0);
+        let mut values = range_step(start, end, step);
+        assert_almost_eq(values.next().unwrap().as_meters(), 1.0);
+        assert_almost_eq(values.next().unwrap().as_meters(), 0.5);
+        assert!(values.next().is_none());
+    }
+
+    #[test]
+    fn range_step_empty() {
+        let start = Length::from_meters(0.0);
+        let end = Length::from_meters(1.0);
+        assert!(range_step(start, end, Length::from_meters(0.0))
+            .next()
+            .is_none());
+        assert!(range_step(start, end, Length::from_meters(-1.0))
+            .next()
+            .is_none());
+        assert!(range_step(end, end, Length::from_meters(1.0))
+            .next()
+            .is_none());
+    }
+}
@@ -0,0 +1,231 @@
+//! Types and constants for handling dimensionless ratios.
+
+use super::measurement::*;
+
+/// The `Ratio` struct can be used to deal with dimensionless factors (gear
+/// ratios, efficiencies, scale factors, etc.) in a common way.
+///
+/// Any measurement can be multiplied or divided by a `Ratio`, which makes it
+/// explicit that the factor has no dimension. With the `typed_ratio` feature
+/// enabled, dividing a measurement by another of the same type also returns a
+/// `Ratio` rather than a bare `f64`.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{AngularVelocity, Ratio};
+///
+/// let motor = AngularVelocity::from_rpm(3000.0);
+/// let gearbox = Ratio::from_ratio(3.0);
+/// let output = motor / gearbox;
+/// println!("The output shaft turns at {} rpm.", output.as_rpm());
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct Ratio {
+    ratio: f64,
+}
+
+impl Ratio {
+    /// Create a new Ratio from a floating point value (e.g. 0.5 for one half)
+    pub fn from_ratio(ratio: f64) -> Self {
+        Ratio { ratio }
+    }
+
+    /// Convert this Ratio to a floating point value (e.g. 0.5 for one half)
+    pub fn as_ratio(&self) -> f64 {
+        self.ratio
+    }
+}
+
+impl Measurement for Ratio {
+    fn as_base_units(&self) -> f64 {
+        self.ratio
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_ratio(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        ""
+    }
+
+    fn quantity_name() -> &'static str {
+        "Ratio"
+    }
+}
+
+impl ::std::convert::From<f64> for Ratio {
+    fn from(ratio: f64) -> Ratio {
+        Ratio::from_ratio(ratio)
+    }
+}
+
+impl ::std::convert::From<Ratio> for f64 {
+    fn from(ratio: Ratio) -> f64 {
+        ratio.ratio
+    }
+}
+
+// A `Ratio` has no unit, so only the value is displayed.
+impl ::std::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        self.ratio.fmt(f)
+    }
+}
+
+implement_debug! { Ratio, ratio }
+
+// `Ratio` can't use `implement_measurement!`, as that would implement
+// `Mul<Ratio>` and `Div<Ratio>` for `Ratio` twice.
+
+impl ::std::ops::Add for Ratio {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::from_ratio(self.ratio + rhs.ratio)
+    }
+}
+
+impl ::std::ops::Sub for Ratio {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_ratio(self.ratio - rhs.ratio)
+    }
+}
+
+impl ::std::ops::Mul<Ratio> for Ratio {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_ratio(self.ratio * rhs.ratio)
+    }
+}
+
+impl ::std::ops::Div<Ratio> for Ratio {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::from_ratio(self.ratio / rhs.ratio)
+    }
+}
+
+impl ::std::ops::Mul<f64> for Ratio {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::from_ratio(self.ratio * rhs)
+    }
+}
+
+impl ::std::ops::Mul<Ratio> for f64 {
+    type Output = Ratio;
+
+    fn mul(self, rhs: Ratio) -> Ratio {
+        rhs * self
+    }
+}
+
+impl ::std::ops::Div<f64> for Ratio {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self::from_ratio(self.ratio / rhs)
+    }
+}
+
+impl ::std::cmp::Eq for Ratio {}
+impl ::std::cmp::PartialEq for Ratio {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_base_units() == other.as_base_units()
+    }
+}
+
+impl ::std::cmp::PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        self.as_base_units().partial_cmp(&other.as_base_units())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use angular_velocity::AngularVelocity;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn ratio() {
+        let r = Ratio::from_ratio(0.25);
+        assert_almost_eq(r.as_ratio(), 0.25);
+        assert_almost_eq(f64::from(r), 0.25);
+    }
+
+    #[test]
+    fn gear_reduction() {
+        let motor = AngularVelocity::from_rpm(3000.0);
+        let gearbox = Ratio::from_ratio(3.0);
+        let output = motor / gearbox;
+        assert_almost_eq(output.as_rpm(), 1000.0);
+    }
+
+    #[test]
+    fn gear_multiplication() {
+        let crank = AngularVelocity::from_rpm(90.0);
+        let gearing = Ratio::from_ratio(2.5);
+        let a = crank * gearing;
+        let b = gearing * crank;
+        assert_almost_eq(a.as_rpm(), 225.0);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn compound_gears() {
+        let stage1 = Ratio::from_ratio(4.0);
+        let stage2 = Ratio::from_ratio(2.5);
+        let motor = AngularVelocity::from_rpm(5000.0);
+        let output = motor / (stage1 * stage2);
+        assert_almost_eq(output.as_rpm(), 500.0);
+    }
+
+    #[test]
+    #[cfg(feature = "typed_ratio")]
+    fn self_div_is_ratio() {
+        let output = AngularVelocity::from_rpm(1000.0);
+        let motor = AngularVelocity::from_rpm(3000.0);
+        let ratio: Ratio = motor / output;
+        assert_almost_eq(ratio.as_ratio(), 3.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = Ratio::from_ratio(2.0);
+        let b = Ratio::from_ratio(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_ratio(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn mul() {
+        let a = Ratio::from_ratio(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_ratio(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = Ratio::from_ratio(2.0);
+        let b = Ratio::from_ratio(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
@@ -1,6 +1,22 @@
 //! Types and constants for handling electrical resistance.
 
 use super::measurement::*;
+use area::Area;
+use capacitance::Capacitance;
+use current::Current;
+use length::Length;
+use power::Power;
+use temperature::Temperature;
+use time;
+use voltage::Voltage;
+
+/// Units supported by [`Resistance`], as (symbol, long name, number of ohms
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("\u{2126}", "ohms", 1.0),
+    ("k\u{2126}", "kiloohms", 1e3),
+    ("M\u{2126}", "megaohms", 1e6),
+];
 
 /// The `Resistance` struct can be used to deal with electrical resistance in a
 /// common way.
@@ -16,7 +32,8 @@ use super::measurement::*;
 /// println!("A 4.7 kΩ resistor has {} Ω or {} MΩ", o, mo);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Resistance {
     ohms: f64,
 }
@@ -37,6 +54,20 @@ impl Resistance {
         Self::from_ohms(megaohms * 1000.0 * 1000.0)
     }
 
+    /// Create a new Resistance for a wire of the given material, length and
+    /// cross-sectional area (ρ × L / A). Copper has a resistivity of about
+    /// 1.68e-8 Ω·m at 20 °C. Returns `None` if the area is not positive.
+    pub fn of_wire(resistivity_ohm_meters: f64, length: Length, area: Area) -> Option<Self> {
+        let area = area.as_square_meters();
+        if area > 0.0 {
+            Some(Self::from_ohms(
+                resistivity_ohm_meters * length.as_meters() / area,
+            ))
+        } else {
+            None
+        }
+    }
+
     /// Convert this Resistance into a floating point value in ohms
     pub fn as_ohms(&self) -> f64 {
         self.ohms
@@ -51,9 +82,61 @@ impl Resistance {
     pub fn as_megaohms(&self) -> f64 {
         self.ohms / 1000.0 / 1000.0
     }
+
+    /// Calculate the resistance at the `target` temperature, if this is the
+    /// resistance at the `reference` temperature, using a linear temperature
+    /// coefficient: R × (1 + α × ΔT). The coefficient `alpha_per_kelvin` is
+    /// about 0.00393 for copper and 0.0039 for aluminium.
+    pub fn at_temperature(
+        &self,
+        reference: Temperature,
+        target: Temperature,
+        alpha_per_kelvin: f64,
+    ) -> Resistance {
+        let delta = target - reference;
+        Self::from_ohms(self.ohms * (1.0 + alpha_per_kelvin * delta.as_kelvin()))
+    }
+
+    /// Calculate the time constant of this Resistance with the given
+    /// Capacitance (τ = RC)
+    pub fn time_constant_with(&self, c: Capacitance) -> time::Duration {
+        time::Duration::from_base_units(self.ohms * c.as_farads())
+    }
+
+    /// Calculate the largest Current this Resistance can carry without
+    /// dissipating more than the given power rating (√(P / R))
+    #[cfg(feature = "std")]
+    pub fn max_current_for_power(&self, p: Power) -> Current {
+        Current::from_amperes((p.as_watts() / self.ohms).sqrt())
+    }
+
+    /// Calculate the largest Current this Resistance can carry without
+    /// dissipating more than the given power rating (√(P / R))
+    #[cfg(not(feature = "std"))]
+    pub fn max_current_for_power(&self, p: Power) -> Current {
+        Current::from_amperes(libm::sqrt(p.as_watts() / self.ohms))
+    }
+
+    /// Calculate the largest Voltage that can be applied across this
+    /// Resistance without it dissipating more than the given power rating
+    /// (√(P × R))
+    #[cfg(feature = "std")]
+    pub fn max_voltage_for_power(&self, p: Power) -> Voltage {
+        Voltage::from_volts((p.as_watts() * self.ohms).sqrt())
+    }
+
+    /// Calculate the largest Voltage that can be applied across this
+    /// Resistance without it dissipating more than the given power rating
+    /// (√(P × R))
+    #[cfg(not(feature = "std"))]
+    pub fn max_voltage_for_power(&self, p: Power) -> Voltage {
+        Voltage::from_volts(libm::sqrt(p.as_watts() * self.ohms))
+    }
 }
 
 impl Measurement for Resistance {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.ohms
     }
@@ -84,8 +167,14 @@ impl Measurement for Resistance {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Resistance"
+    }
 }
 
+implement_debug! { Resistance, ohms }
+
 implement_measurement! { Resistance }
 
 #[cfg(test)]
@@ -111,6 +200,47 @@ mod test {
         assert_almost_eq(u.as_megaohms(), 1.234567);
     }
 
+    #[test]
+    fn of_wire() {
+        let r = Resistance::of_wire(
+            1.68e-8,
+            Length::from_meters(100.0),
+            Area::from_square_millimeters(2.5),
+        );
+        assert_almost_eq(r.unwrap().as_ohms(), 0.672);
+        let r = Resistance::of_wire(1.68e-8, Length::from_meters(100.0), Area::default());
+        assert_eq!(r, None);
+    }
+
+    #[test]
+    fn at_temperature() {
+        let r = Resistance::from_ohms(100.0);
+        let reference = Temperature::from_celsius(20.0);
+        let target = Temperature::from_celsius(70.0);
+        let hot = r.at_temperature(reference, target, 0.00393);
+        assert_almost_eq(hot.as_ohms(), 119.65);
+        let cold = hot.at_temperature(target, reference, 0.00393);
+        assert_almost_eq(
+            cold.as_ohms(),
+            100.0 * (1.0 + 0.00393 * 50.0) * (1.0 - 0.00393 * 50.0),
+        );
+    }
+
+    #[test]
+    fn time_constant_with() {
+        let r = Resistance::from_kiloohms(1.0);
+        let tau = r.time_constant_with(Capacitance::from_microfarads(1.0));
+        assert_almost_eq(tau.as_base_units(), 1e-3);
+    }
+
+    #[test]
+    fn power_limits() {
+        let r = Resistance::from_ohms(100.0);
+        let p = Power::from_watts(0.25);
+        assert_almost_eq(r.max_current_for_power(p).as_milliamperes(), 50.0);
+        assert_almost_eq(r.max_voltage_for_power(p).as_volts(), 5.0);
+    }
+
     // Traits
     #[test]
     fn add() {
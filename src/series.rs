@@ -0,0 +1,179 @@
+//! Extensions for working with series of measurements, such as a log of
+//! readings.
+
+use super::measurement::*;
+use std::ops::Sub;
+
+/// Extension methods for iterators over measurements. This is implemented for
+/// every iterator whose items implement `Measurement`.
+pub trait MeasurementIterator: Iterator + Sized
+where
+    Self::Item: Measurement,
+{
+    /// Returns an iterator over the running total of the measurements,
+    /// summed in base units, e.g. to plot the cumulative distance of a trip
+    /// from the length of each leg.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use measurements::prelude::*;
+    /// use measurements::Length;
+    ///
+    /// let legs = [Length::from_kilometers(3.0), Length::from_kilometers(5.0)];
+    /// for total in legs.iter().cloned().cumulative_sum() {
+    ///     println!("{:.1} km so far", total.as_kilometers());
+    /// }
+    /// ```
+    fn cumulative_sum(self) -> CumulativeSum<Self> {
+        CumulativeSum {
+            iter: self,
+            total: 0.0,
+        }
+    }
+
+    /// Returns an iterator over the differences between each measurement and
+    /// the one before it, e.g. to turn a series of odometer readings into the
+    /// distance covered in each interval. A series of `n` measurements gives
+    /// `n - 1` differences.
+    ///
+    /// The differences have the type given by subtracting two measurements,
+    /// so a series of `Temperature` readings gives `TemperatureDelta`s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use measurements::prelude::*;
+    /// use measurements::Temperature;
+    ///
+    /// let readings = [Temperature::from_celsius(20.0), Temperature::from_celsius(22.5)];
+    /// for change in readings.iter().cloned().diffs() {
+    ///     println!("{:.1} K warmer", change.as_kelvin());
+    /// }
+    /// ```
+    fn diffs(self) -> Diffs<Self>
+    where
+        Self::Item: Sub + Copy,
+    {
+        Diffs {
+            iter: self,
+            previous: None,
+        }
+    }
+}
+
+impl<I> MeasurementIterator for I
+where
+    I: Iterator,
+    I::Item: Measurement,
+{
+}
+
+/// An iterator over the running total of a series of measurements. See
+/// [`MeasurementIterator::cumulative_sum`].
+#[derive(Clone, Debug)]
+pub struct CumulativeSum<I> {
+    iter: I,
+    total: f64,
+}
+
+impl<I> Iterator for CumulativeSum<I>
+where
+    I: Iterator,
+    I::Item: Measurement,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        self.total += item.as_base_units();
+        Some(I::Item::from_base_units(self.total))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator over the differences between successive measurements. See
+/// [`MeasurementIterator::diffs`].
+#[derive(Clone, Debug)]
+pub struct Diffs<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I> Iterator for Diffs<I>
+where
+    I: Iterator,
+    I::Item: Sub + Copy,
+{
+    type Item = <I::Item as Sub>::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+        let current = self.iter.next()?;
+        self.previous = Some(current);
+        Some(current - previous)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use length::Length;
+    use temperature::{Temperature, TemperatureDelta};
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn cumulative_sum() {
+        let legs = [Length::from_meters(10.0); 3];
+        let mut totals = legs.iter().cloned().cumulative_sum();
+        assert_almost_eq(totals.next().unwrap().as_meters(), 10.0);
+        assert_almost_eq(totals.next().unwrap().as_meters(), 20.0);
+        assert_almost_eq(totals.next().unwrap().as_meters(), 30.0);
+        assert!(totals.next().is_none());
+    }
+
+    #[test]
+    fn diffs() {
+        let odometer = [
+            Length::from_kilometers(100.0),
+            Length::from_kilometers(112.0),
+            Length::from_kilometers(112.0),
+            Length::from_kilometers(130.5),
+        ];
+        let mut legs = odometer.iter().cloned().diffs();
+        assert_almost_eq(legs.next().unwrap().as_kilometers(), 12.0);
+        assert_eq!(legs.next().unwrap().as_kilometers(), 0.0);
+        assert_almost_eq(legs.next().unwrap().as_kilometers(), 18.5);
+        assert!(legs.next().is_none());
+    }
+
+    #[test]
+    fn diffs_short() {
+        let one = [Length::from_meters(1.0)];
+        assert!(one.iter().cloned().diffs().next().is_none());
+        let none: [Length; 0] = [];
+        assert!(none.iter().cloned().diffs().next().is_none());
+    }
+
+    #[test]
+    fn diffs_temperature() {
+        let readings = [
+            Temperature::from_celsius(20.0),
+            Temperature::from_celsius(18.0),
+        ];
+        let change: TemperatureDelta = readings.iter().cloned().diffs().next().unwrap();
+        assert_almost_eq(change.as_kelvin(), -2.0);
+    }
+
+    #[test]
+    fn cumulative_sum_empty() {
+        let legs: [Length; 0] = [];
+        assert!(legs.iter().cloned().cumulative_sum().next().is_none());
+    }
+}
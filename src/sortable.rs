@@ -0,0 +1,144 @@
+//! A wrapper giving measurements a total order.
+
+use super::measurement::*;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+/// The `SortableMeasurement` struct wraps a measurement so that it can be
+/// used where a total order is required, such as a key in a `BTreeMap` or
+/// `BTreeSet`, or a key in a `HashMap`.
+///
+/// Measurements are compared by their value in base units. NaN is equal to
+/// NaN and greater than any other value, and positive and negative zero are
+/// equal.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Length, SortableMeasurement};
+/// use std::collections::BTreeSet;
+///
+/// let mut lengths = BTreeSet::new();
+/// lengths.insert(SortableMeasurement::from(Length::from_meters(3.0)));
+/// lengths.insert(SortableMeasurement::from(Length::from_feet(3.0)));
+/// let shortest = lengths.iter().next().unwrap().into_inner();
+/// println!("The shortest length is {}.", shortest);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SortableMeasurement<M>(pub M);
+
+impl<M> SortableMeasurement<M> {
+    /// Wrap a measurement
+    pub fn new(measurement: M) -> Self {
+        SortableMeasurement(measurement)
+    }
+
+    /// Unwrap the measurement
+    pub fn into_inner(self) -> M {
+        self.0
+    }
+}
+
+impl<M: Measurement> SortableMeasurement<M> {
+    /// Get the wrapped measurement in its base units, with all NaNs and
+    /// both zeros replaced by a single value each.
+    fn canonical_base_units(&self) -> f64 {
+        let value = self.0.as_base_units();
+        if value.is_nan() {
+            f64::NAN
+        } else if value == 0.0 {
+            0.0
+        } else {
+            value
+        }
+    }
+}
+
+impl<M> ::std::convert::From<M> for SortableMeasurement<M> {
+    fn from(measurement: M) -> Self {
+        SortableMeasurement(measurement)
+    }
+}
+
+impl<M: ::std::fmt::Display> ::std::fmt::Display for SortableMeasurement<M> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<M: Measurement> ::std::cmp::Ord for SortableMeasurement<M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let a = self.canonical_base_units();
+        let b = other.canonical_base_units();
+        match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+impl<M: Measurement> ::std::cmp::PartialOrd for SortableMeasurement<M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M: Measurement> ::std::cmp::Eq for SortableMeasurement<M> {}
+impl<M: Measurement> ::std::cmp::PartialEq for SortableMeasurement<M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<M: Measurement> Hash for SortableMeasurement<M> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical_base_units().to_bits().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use length::Length;
+
+    #[test]
+    fn into_inner() {
+        let s = SortableMeasurement::from(Length::from_meters(2.0));
+        assert_eq!(s.into_inner(), Length::from_meters(2.0));
+        let s: SortableMeasurement<Length> = Length::from_meters(2.0).into();
+        assert_eq!(s.0, Length::from_meters(2.0));
+    }
+
+    #[test]
+    fn cmp() {
+        let a = SortableMeasurement::new(Length::from_meters(1.0));
+        let b = SortableMeasurement::new(Length::from_kilometers(1.0));
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(b.cmp(&a), Ordering::Greater);
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+    }
+
+    #[test]
+    fn nan_is_largest() {
+        let nan = SortableMeasurement::new(Length::from_meters(f64::NAN));
+        let inf = SortableMeasurement::new(Length::from_meters(f64::INFINITY));
+        assert_eq!(nan.cmp(&inf), Ordering::Greater);
+        assert_eq!(inf.cmp(&nan), Ordering::Less);
+        assert_eq!(nan, nan);
+    }
+
+    #[test]
+    fn zeros_are_equal() {
+        let a = SortableMeasurement::new(Length::from_meters(0.0));
+        let b = SortableMeasurement::new(Length::from_meters(-0.0));
+        assert_eq!(a, b);
+        assert_eq!(
+            a.canonical_base_units().to_bits(),
+            b.canonical_base_units().to_bits()
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! Types and constants for handling specific energy.
+
+use super::measurement::*;
+
+/// Units supported by [`SpecificEnergy`], as (symbol, long name, number of
+/// joules per kilogram in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("J/kg", "joules per kilogram", 1.0),
+    ("kJ/kg", "kilojoules per kilogram", 1e3),
+    ("Wh/kg", "watt hours per kilogram", 3600.0),
+    ("MJ/kg", "megajoules per kilogram", 1e6),
+];
+
+/// The `SpecificEnergy` struct can be used to deal with energy per unit mass
+/// in a common way, such as the heating value of a fuel or the energy density
+/// of a battery.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Energy, Mass, SpecificEnergy};
+///
+/// let diesel = SpecificEnergy::from_megajoules_per_kilogram(42.6);
+/// let heat: Energy = diesel * Mass::from_kilograms(10.0);
+/// println!("Burning 10 kg of diesel releases {}.", heat);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct SpecificEnergy {
+    joules_per_kilogram: f64,
+}
+
+impl SpecificEnergy {
+    /// Create a new SpecificEnergy from a floating point value in joules per kilogram
+    pub fn from_joules_per_kilogram(joules_per_kilogram: f64) -> Self {
+        SpecificEnergy {
+            joules_per_kilogram,
+        }
+    }
+
+    /// Create a new SpecificEnergy from a floating point value in kilojoules per kilogram
+    pub fn from_kilojoules_per_kilogram(kilojoules_per_kilogram: f64) -> Self {
+        Self::from_joules_per_kilogram(kilojoules_per_kilogram * 1e3)
+    }
+
+    /// Create a new SpecificEnergy from a floating point value in megajoules per kilogram
+    pub fn from_megajoules_per_kilogram(megajoules_per_kilogram: f64) -> Self {
+        Self::from_joules_per_kilogram(megajoules_per_kilogram * 1e6)
+    }
+
+    /// Create a new SpecificEnergy from a floating point value in watt hours per kilogram
+    pub fn from_watt_hours_per_kilogram(watt_hours_per_kilogram: f64) -> Self {
+        Self::from_joules_per_kilogram(watt_hours_per_kilogram * 3600.0)
+    }
+
+    /// Convert this SpecificEnergy into a floating point value in joules per kilogram
+    pub fn as_joules_per_kilogram(&self) -> f64 {
+        self.joules_per_kilogram
+    }
+
+    /// Convert this SpecificEnergy into a floating point value in kilojoules per kilogram
+    pub fn as_kilojoules_per_kilogram(&self) -> f64 {
+        self.joules_per_kilogram / 1e3
+    }
+
+    /// Convert this SpecificEnergy into a floating point value in megajoules per kilogram
+    pub fn as_megajoules_per_kilogram(&self) -> f64 {
+        self.joules_per_kilogram / 1e6
+    }
+
+    /// Convert this SpecificEnergy into a floating point value in watt hours per kilogram
+    pub fn as_watt_hours_per_kilogram(&self) -> f64 {
+        self.joules_per_kilogram / 3600.0
+    }
+}
+
+impl Measurement for SpecificEnergy {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.joules_per_kilogram
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_joules_per_kilogram(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "J/kg"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [("J/kg", 1e0), ("kJ/kg", 1e3), ("MJ/kg", 1e6)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "SpecificEnergy"
+    }
+}
+
+implement_debug! { SpecificEnergy, joules_per_kilogram }
+
+implement_measurement! { SpecificEnergy }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use energy::Energy;
+    use mass::Mass;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn megajoules_per_kilogram() {
+        let i1 = SpecificEnergy::from_megajoules_per_kilogram(42.6);
+        let r1 = i1.as_kilojoules_per_kilogram();
+        let i2 = SpecificEnergy::from_watt_hours_per_kilogram(250.0);
+        let r2 = i2.as_megajoules_per_kilogram();
+        assert_almost_eq(r1, 42_600.0);
+        assert_almost_eq(r2, 0.9);
+    }
+
+    #[test]
+    fn energy_of_mass() {
+        let lhv = SpecificEnergy::from_megajoules_per_kilogram(42.6);
+        let e: Energy = lhv * Mass::from_kilograms(2.0);
+        assert_almost_eq(e.as_joules(), 85.2e6);
+        let m: Mass = e / lhv;
+        assert_almost_eq(m.as_kilograms(), 2.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = SpecificEnergy::from_joules_per_kilogram(2.0);
+        let b = SpecificEnergy::from_joules_per_kilogram(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_joules_per_kilogram(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = SpecificEnergy::from_joules_per_kilogram(2.0);
+        let b = SpecificEnergy::from_joules_per_kilogram(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_joules_per_kilogram(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = SpecificEnergy::from_joules_per_kilogram(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_joules_per_kilogram(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = SpecificEnergy::from_joules_per_kilogram(2.0);
+        let b = SpecificEnergy::from_joules_per_kilogram(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_joules_per_kilogram(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = SpecificEnergy::from_joules_per_kilogram(2.0);
+        let b = SpecificEnergy::from_joules_per_kilogram(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = SpecificEnergy::from_joules_per_kilogram(2.0);
+        let b = SpecificEnergy::from_joules_per_kilogram(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = SpecificEnergy::from_joules_per_kilogram(2.0);
+        let b = SpecificEnergy::from_joules_per_kilogram(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
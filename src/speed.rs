@@ -2,6 +2,7 @@
 
 use super::measurement::*;
 use super::*;
+use angle::Angle;
 
 /// Number of seconds in a minute
 pub const SECONDS_MINUTES_FACTOR: f64 = 60.0;
@@ -10,6 +11,14 @@ pub const MINUTES_HOURS_FACTOR: f64 = 60.0;
 /// Number of seconds in a hour
 pub const SECONDS_HOURS_FACTOR: f64 = 60.0 * 60.0;
 
+/// Units supported by [`Speed`], as (symbol, long name, number of meters per second
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("m/s", "meters per second", 1.0),
+    ("km/h", "kilometers per hour", 1000.0 / SECONDS_HOURS_FACTOR),
+    ("mph", "miles per hour", 1609.0 / SECONDS_HOURS_FACTOR),
+];
+
 /// The `Speed` struct can be used to deal with speeds in a common way.
 /// Common metric and imperial units are supported.
 ///
@@ -23,7 +32,8 @@ pub const SECONDS_HOURS_FACTOR: f64 = 60.0 * 60.0;
 /// println!("The speed of light is {} mph.", mph);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Speed {
     meters_per_second: f64,
 }
@@ -80,9 +90,96 @@ impl Speed {
     pub fn as_miles_per_hour(&self) -> f64 {
         (self.meters_per_second / 1609.0) * 3600.0
     }
+
+    /// Calculate the vertical component of this Speed along a slope at the
+    /// given angle, e.g. the climb rate from a ground speed (v × sin θ)
+    #[cfg(feature = "std")]
+    pub fn vertical_component(&self, grade: Angle) -> Speed {
+        Speed::from_meters_per_second(self.meters_per_second * grade.as_radians().sin())
+    }
+
+    /// Calculate the vertical component of this Speed along a slope at the
+    /// given angle, e.g. the climb rate from a ground speed (v × sin θ)
+    #[cfg(not(feature = "std"))]
+    pub fn vertical_component(&self, grade: Angle) -> Speed {
+        Speed::from_meters_per_second(self.meters_per_second * libm::sin(grade.as_radians()))
+    }
+
+    /// Calculate the horizontal component of this Speed along a slope at the
+    /// given angle (v × cos θ)
+    #[cfg(feature = "std")]
+    pub fn horizontal_component(&self, grade: Angle) -> Speed {
+        Speed::from_meters_per_second(self.meters_per_second * grade.as_radians().cos())
+    }
+
+    /// Calculate the horizontal component of this Speed along a slope at the
+    /// given angle (v × cos θ)
+    #[cfg(not(feature = "std"))]
+    pub fn horizontal_component(&self, grade: Angle) -> Speed {
+        Speed::from_meters_per_second(self.meters_per_second * libm::cos(grade.as_radians()))
+    }
+
+    /// Calculate the Speed of a circular orbit of the given radius (measured
+    /// from the centre of the body) around a body of the given Mass (√(GM / r))
+    #[cfg(feature = "std")]
+    pub fn circular_orbit(central_mass: Mass, radius: Length) -> Speed {
+        Speed::from_meters_per_second(
+            (constants::GRAVITATIONAL_CONSTANT * central_mass.as_kilograms() / radius.as_meters())
+                .sqrt(),
+        )
+    }
+
+    /// Calculate the Speed of a circular orbit of the given radius (measured
+    /// from the centre of the body) around a body of the given Mass (√(GM / r))
+    #[cfg(not(feature = "std"))]
+    pub fn circular_orbit(central_mass: Mass, radius: Length) -> Speed {
+        Speed::from_meters_per_second(libm::sqrt(
+            constants::GRAVITATIONAL_CONSTANT * central_mass.as_kilograms() / radius.as_meters(),
+        ))
+    }
+
+    /// Calculate the distance needed to stop from this Speed at the given
+    /// constant deceleration (v² / 2a). The deceleration may be given as a
+    /// positive or negative value. Returns `None` if it is zero, as the
+    /// vehicle would never stop.
+    pub fn stopping_distance(&self, deceleration: Acceleration) -> Option<Length> {
+        let a = deceleration.as_meters_per_second_per_second();
+        if a == 0.0 {
+            return None;
+        }
+        let a = if a < 0.0 { -a } else { a };
+        let v = self.meters_per_second;
+        Some(Length::from_meters(v * v / (2.0 * a)))
+    }
+
+    /// Calculate the time needed to stop from this Speed at the given
+    /// constant deceleration (v / a). The speed and deceleration may be given
+    /// as positive or negative values. Returns `None` if the deceleration is
+    /// zero, as the vehicle would never stop.
+    pub fn stopping_time(&self, deceleration: Acceleration) -> Option<time::Duration> {
+        let a = deceleration.as_meters_per_second_per_second();
+        if a == 0.0 {
+            return None;
+        }
+        let ratio = self.meters_per_second / a;
+        Some(time::Duration::from_base_units(if ratio < 0.0 {
+            -ratio
+        } else {
+            ratio
+        }))
+    }
+
+    /// Square this Speed (v²). This has the units of a SpecificEnergy, so
+    /// half of it is the kinetic energy per kilogram of a body moving at this
+    /// Speed.
+    pub fn squared(&self) -> SpecificEnergy {
+        SpecificEnergy::from_joules_per_kilogram(self.meters_per_second * self.meters_per_second)
+    }
 }
 
 impl Measurement for Speed {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.meters_per_second
     }
@@ -108,8 +205,14 @@ impl Measurement for Speed {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Speed"
+    }
 }
 
+implement_debug! { Speed, meters_per_second }
+
 implement_measurement! { Speed }
 
 #[cfg(test)]
@@ -171,8 +274,57 @@ mod test {
         let i2 = Speed::from_miles_per_hour(100.0);
         let r2 = i2.as_meters_per_second();
 
-        assert_almost_eq(r1, 223.7414543194530764449968924798);
-        assert_almost_eq(r2, 44.694444444444444444444444444444);
+        assert_almost_eq(r1, 223.74145431945308);
+        assert_almost_eq(r2, 44.69444444444444);
+    }
+
+    #[test]
+    fn value_in() {
+        let s = Speed::from_miles_per_hour(60.0);
+        assert_almost_eq(s.value_in("mph").unwrap(), 60.0);
+        assert_almost_eq(s.value_in("km/h").unwrap(), 96.54);
+        assert_eq!(s.value_in("xyz"), None);
+    }
+
+    #[test]
+    fn components() {
+        let s = Speed::from_meters_per_second(10.0);
+        let slope = Angle::from_degrees(30.0);
+        assert_almost_eq(s.vertical_component(slope).as_meters_per_second(), 5.0);
+        assert_almost_eq(
+            s.horizontal_component(slope).as_meters_per_second(),
+            8.660254,
+        );
+    }
+
+    #[test]
+    fn stopping() {
+        let v = Speed::from_meters_per_second(20.0);
+        let a = Acceleration::from_meters_per_second_per_second(5.0);
+        assert_almost_eq(v.stopping_distance(a).unwrap().as_meters(), 40.0);
+        assert_eq!(v.stopping_time(a), Some(::time::Duration::from_secs(4)));
+        let a = Acceleration::from_meters_per_second_per_second(-5.0);
+        assert_almost_eq(v.stopping_distance(a).unwrap().as_meters(), 40.0);
+        assert_eq!(v.stopping_time(a), Some(::time::Duration::from_secs(4)));
+        let none = Acceleration::from_meters_per_second_per_second(0.0);
+        assert_eq!(v.stopping_distance(none), None);
+        assert_eq!(v.stopping_time(none), None);
+    }
+
+    #[test]
+    fn circular_orbit() {
+        // Low Earth orbit, 200 km up
+        let radius = ::constants::earth_mean_radius() + Length::from_kilometers(200.0);
+        let v = Speed::circular_orbit(::constants::earth_mass(), radius);
+        assert!((v.as_kilometers_per_hour() / 3600.0 - 7.8).abs() < 0.05);
+    }
+
+    #[test]
+    fn squared() {
+        let v = Speed::from_meters_per_second(20.0);
+        assert_almost_eq(v.squared().as_joules_per_kilogram(), 400.0);
+        let kinetic: Energy = Mass::from_kilograms(1000.0) * v.squared() / 2.0;
+        assert_almost_eq(kinetic.as_joules(), 200_000.0);
     }
 
     // Traits
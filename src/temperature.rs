@@ -1,8 +1,12 @@
 //! Types and constants for handling temperature.
 
 use super::measurement::*;
+use constants::BOLTZMANN_CONSTANT;
+use length::Length;
+use mass::Mass;
 #[cfg(feature = "from_str")]
-use regex::Regex;
+use parse::{parse_number, split_number_unit, strip_degree, unknown_unit, ParseMeasurementError};
+use speed::Speed;
 #[cfg(feature = "from_str")]
 use std::str::FromStr;
 
@@ -19,7 +23,8 @@ use std::str::FromStr;
 /// println!("Boiling water measures at {} degrees fahrenheit.", fahrenheit);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Temperature {
     degrees_kelvin: f64,
 }
@@ -27,6 +32,10 @@ pub struct Temperature {
 /// The `TemperatureDelta` struct can be used to deal with differences between
 /// temperatures in a common way.
 ///
+/// A Kelvin and a degree Celsius are the same size, so a difference of 1 K is
+/// also a difference of 1 °C (and likewise 1 °F and 1 °R), even though the
+/// scales have different zero points.
+///
 /// # Example
 ///
 /// ```
@@ -38,7 +47,8 @@ pub struct Temperature {
 /// println!("Boiling water is {} above freezing.", difference);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct TemperatureDelta {
     kelvin_degrees: f64,
 }
@@ -49,7 +59,8 @@ impl TemperatureDelta {
         TemperatureDelta { kelvin_degrees }
     }
 
-    /// Create a new TemperatureDelta from a floating point value in Celsius
+    /// Create a new TemperatureDelta from a floating point value in Celsius.
+    /// This is the same as in Kelvin, as the degrees are the same size.
     pub fn from_celsius(celsius_degrees: f64) -> Self {
         TemperatureDelta::from_kelvin(celsius_degrees)
     }
@@ -68,12 +79,19 @@ impl TemperatureDelta {
         }
     }
 
+    /// Create a new TemperatureDelta from the change in temperature going
+    /// from `a` to `b` (b - a), which is negative if `b` is colder
+    pub fn from_delta_between(a: Temperature, b: Temperature) -> Self {
+        b - a
+    }
+
     /// Convert this TemperatureDelta to a floating point value in Kelvin
     pub fn as_kelvin(&self) -> f64 {
         self.kelvin_degrees
     }
 
-    /// Convert this TemperatureDelta to a floating point value in Celsius
+    /// Convert this TemperatureDelta to a floating point value in Celsius.
+    /// This is the same as in Kelvin, as the degrees are the same size.
     pub fn as_celsius(&self) -> f64 {
         self.kelvin_degrees
     }
@@ -129,6 +147,39 @@ impl Temperature {
     pub fn as_rankine(&self) -> f64 {
         (self.degrees_kelvin - 273.15) * 1.8 + 491.67
     }
+
+    /// Estimate the height above ground of the base of cumulus clouds from
+    /// this surface air Temperature and the given dewpoint, using the rule of
+    /// thumb of about 125 m for every degree of spread. This is only an
+    /// approximation, for convective clouds in well-mixed air.
+    pub fn cloud_base_agl(&self, dewpoint: Temperature) -> Length {
+        Length::from_meters((self.degrees_kelvin - dewpoint.degrees_kelvin) * 125.0)
+    }
+
+    /// Calculate the Temperature halfway between this one and another, e.g.
+    /// for a control setpoint. Unlike adding two absolute temperatures, this
+    /// is well defined: it is the average in Kelvin.
+    pub fn midpoint(&self, other: Temperature) -> Temperature {
+        Temperature::from_kelvin((self.degrees_kelvin + other.degrees_kelvin) / 2.0)
+    }
+
+    /// Calculate the root-mean-square speed of gas particles (molecules or
+    /// atoms) of the given Mass at this Temperature (√(3kT / m))
+    #[cfg(feature = "std")]
+    pub fn rms_molecular_speed(&self, particle_mass: Mass) -> Speed {
+        Speed::from_meters_per_second(
+            (3.0 * BOLTZMANN_CONSTANT * self.degrees_kelvin / particle_mass.as_kilograms()).sqrt(),
+        )
+    }
+
+    /// Calculate the root-mean-square speed of gas particles (molecules or
+    /// atoms) of the given Mass at this Temperature (√(3kT / m))
+    #[cfg(not(feature = "std"))]
+    pub fn rms_molecular_speed(&self, particle_mass: Mass) -> Speed {
+        Speed::from_meters_per_second(libm::sqrt(
+            3.0 * BOLTZMANN_CONSTANT * self.degrees_kelvin / particle_mass.as_kilograms(),
+        ))
+    }
 }
 
 impl Measurement for Temperature {
@@ -143,6 +194,10 @@ impl Measurement for Temperature {
     fn get_base_units_name(&self) -> &'static str {
         "K"
     }
+
+    fn quantity_name() -> &'static str {
+        "Temperature"
+    }
 }
 
 impl Measurement for TemperatureDelta {
@@ -157,6 +212,10 @@ impl Measurement for TemperatureDelta {
     fn get_base_units_name(&self) -> &'static str {
         "K"
     }
+
+    fn quantity_name() -> &'static str {
+        "TemperatureDelta"
+    }
 }
 
 impl ::std::ops::Add<TemperatureDelta> for Temperature {
@@ -206,34 +265,44 @@ impl ::std::cmp::PartialOrd for Temperature {
 
 #[cfg(feature = "from_str")]
 impl FromStr for Temperature {
-    type Err = std::num::ParseFloatError;
+    type Err = ParseMeasurementError;
 
     /// Create a new Temperature from a string
-    /// Plain numbers in string are considered to be Celsius
+    ///
+    /// **Plain numbers in string are considered to be Celsius**, not Kelvin.
+    ///
+    /// The scale is given by a single letter, optionally after a degree sign
+    /// or "deg", e.g. "20 C", "68°F" or "293.15 K". Celsius, Fahrenheit and
+    /// Rankine may be given in either case, but Kelvin must be an upper case
+    /// "K": a lone "k" is more likely to be a stray kilo prefix, so it is
+    /// rejected as an unknown unit rather than guessed at.
     fn from_str(val: &str) -> Result<Self, Self::Err> {
         if val.is_empty() {
             return Ok(Temperature::from_celsius(0.0));
         }
 
-        let re = Regex::new(r"\s*([0-9.]*)\s?(deg|\u{00B0}|)?\s?([fckrFCKR]{1})\s*$").unwrap();
-        if let Some(caps) = re.captures(val) {
-            let float_val = caps.get(1).unwrap().as_str();
-            return Ok(
-                match caps.get(3).unwrap().as_str().to_uppercase().as_str() {
-                    "F" => Temperature::from_fahrenheit(float_val.parse::<f64>()?),
-                    "C" => Temperature::from_celsius(float_val.parse::<f64>()?),
-                    "K" => Temperature::from_kelvin(float_val.parse::<f64>()?),
-                    "R" => Temperature::from_rankine(float_val.parse::<f64>()?),
-                    _ => Temperature::from_celsius(val.parse::<f64>()?),
-                },
-            );
+        let (number, unit) = split_number_unit(val);
+        if unit.is_empty() {
+            return Ok(Temperature::from_celsius(parse_number(number)?));
         }
-
-        Ok(Temperature::from_celsius(val.parse::<f64>()?))
+        let scale = strip_degree(&unit);
+        if scale == "k" {
+            return Err(unknown_unit(&unit));
+        }
+        Ok(match scale.to_uppercase().as_str() {
+            "F" => Temperature::from_fahrenheit(parse_number(number)?),
+            "C" => Temperature::from_celsius(parse_number(number)?),
+            "K" => Temperature::from_kelvin(parse_number(number)?),
+            "R" => Temperature::from_rankine(parse_number(number)?),
+            _ => return Err(unknown_unit(&unit)),
+        })
     }
 }
 
+implement_debug!(Temperature, degrees_kelvin);
 implement_display!(Temperature);
+
+implement_debug!(TemperatureDelta, kelvin_degrees);
 implement_measurement!(TemperatureDelta);
 
 #[cfg(test)]
@@ -381,6 +450,89 @@ mod test {
         assert!(t.is_err());
     }
 
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn kelvin_str() {
+        let t = Temperature::from_str("100 K");
+        assert_almost_eq(t.unwrap().as_kelvin(), 100.0);
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn lowercase_kelvin_str_is_ambiguous() {
+        let t = Temperature::from_str("100 k");
+        assert_eq!(t, Err(ParseMeasurementError::UnknownUnit("k".to_string())));
+        assert!(Temperature::from_str("100k").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn prefixed_str_is_rejected() {
+        assert!(Temperature::from_str("100 kC").is_err());
+        assert!(Temperature::from_str("100 mK").is_err());
+    }
+
+    #[test]
+    fn rms_molecular_speed() {
+        // One nitrogen molecule at room temperature
+        let n2 = Mass::from_kilograms(0.028_014 / ::constants::AVOGADRO_CONSTANT);
+        let v = Temperature::from_celsius(25.0).rms_molecular_speed(n2);
+        assert!((v.as_meters_per_second() - 515.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn midpoint() {
+        let a = Temperature::from_celsius(0.0);
+        let b = Temperature::from_celsius(100.0);
+        assert_almost_eq(a.midpoint(b).as_celsius(), 50.0);
+        assert_eq!(a.midpoint(b), b.midpoint(a));
+        let c = Temperature::from_fahrenheit(-40.0);
+        assert_almost_eq(c.midpoint(c).as_celsius(), -40.0);
+    }
+
+    #[test]
+    fn delta_between() {
+        let a = Temperature::from_celsius(20.0);
+        let b = Temperature::from_celsius(30.0);
+        let d = TemperatureDelta::from_delta_between(a, b);
+        assert_almost_eq(d.as_kelvin(), 10.0);
+        assert_almost_eq(d.as_celsius(), 10.0);
+        assert_almost_eq(d.as_fahrenheit(), 18.0);
+        assert_almost_eq(d.as_rankine(), 18.0);
+        assert_almost_eq(
+            TemperatureDelta::from_delta_between(b, a).as_kelvin(),
+            -10.0,
+        );
+        assert_almost_eq((a + d).as_celsius(), 30.0);
+        assert_almost_eq(TemperatureDelta::from_fahrenheit(18.0).as_kelvin(), 10.0);
+    }
+
+    #[test]
+    fn cloud_base_agl() {
+        let t = Temperature::from_celsius(25.0);
+        let base = t.cloud_base_agl(Temperature::from_celsius(15.0));
+        assert_almost_eq(base.as_meters(), 1250.0);
+    }
+
+    #[test]
+    fn split_sign() {
+        let cooling = TemperatureDelta::from_celsius(-3.5);
+        let (negative, magnitude) = cooling.split_sign();
+        assert!(negative);
+        assert_almost_eq(magnitude.as_celsius(), 3.5);
+        let (negative, magnitude) = TemperatureDelta::from_fahrenheit(9.0).split_sign();
+        assert!(!negative);
+        assert_almost_eq(magnitude.as_kelvin(), 5.0);
+        let (negative, _) = Temperature::from_celsius(-10.0).split_sign();
+        assert!(!negative);
+        let (negative, magnitude) = TemperatureDelta::from_kelvin(-0.0).split_sign();
+        assert!(!negative);
+        assert_eq!(magnitude.as_kelvin(), 0.0);
+        let (negative, magnitude) = TemperatureDelta::from_kelvin(-f64::NAN).split_sign();
+        assert!(!negative);
+        assert!(magnitude.as_kelvin().is_nan());
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -13,12 +13,15 @@ pub fn almost_eq_delta(a: f64, b: f64, d: f64) -> bool {
 }
 
 /// Assert two floating point values are approximately equal
-pub fn assert_almost_eq(a: f64, b: f64) {
+///
+/// The first value may be anything convertible to `f64`, such as a `Ratio`.
+pub fn assert_almost_eq<A: Into<f64>>(a: A, b: f64) {
     assert_almost_eq_delta(a, b, DEFAULT_DELTA);
 }
 
 /// Assert two floating point values are approximately equal using some given delta (a fraction of the inputs)
-pub fn assert_almost_eq_delta(a: f64, b: f64, d: f64) {
+pub fn assert_almost_eq_delta<A: Into<f64>>(a: A, b: f64, d: f64) {
+    let a = a.into();
     if !almost_eq_delta(a, b, d) {
         panic!("assertion failed: {:?} != {:?} (within {:?})", a, b, d);
     }
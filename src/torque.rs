@@ -5,6 +5,17 @@ use super::measurement::*;
 /// Number of pound-foot in a newton-metre
 const NEWTON_METRE_POUND_FOOT_FACTOR: f64 = 0.73756326522588;
 
+/// Units supported by [`Torque`], as (symbol, long name, number of newton metres
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("Nm", "newton metres", 1.0),
+    (
+        "lbf\u{00B7}ft",
+        "pound feet",
+        1.0 / NEWTON_METRE_POUND_FOOT_FACTOR,
+    ),
+];
+
 /// The 'Torque' struct can be used to deal with torque in a common way.
 ///
 /// # Example
@@ -16,7 +27,8 @@ const NEWTON_METRE_POUND_FOOT_FACTOR: f64 = 0.73756326522588;
 /// println!("In metric, that's {} Nm", engine_torque.as_newton_metres());
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Torque {
     newton_metres: f64,
 }
@@ -54,6 +66,8 @@ impl Torque {
 }
 
 impl Measurement for Torque {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.newton_metres
     }
@@ -65,8 +79,14 @@ impl Measurement for Torque {
     fn get_base_units_name(&self) -> &'static str {
         "Nm"
     }
+
+    fn quantity_name() -> &'static str {
+        "Torque"
+    }
 }
 
+implement_debug! { Torque, newton_metres }
+
 implement_measurement! { Torque }
 
 #[cfg(test)]
@@ -8,6 +8,8 @@ use super::*;
 /// from the multiplication, and you have to then convert
 /// it to whichever you want.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct TorqueEnergy {
     newton_metres: f64,
 }
@@ -38,4 +40,31 @@ impl Measurement for TorqueEnergy {
     fn get_base_units_name(&self) -> &'static str {
         "Nm||J"
     }
+
+    fn quantity_name() -> &'static str {
+        "TorqueEnergy"
+    }
+}
+
+implement_debug! { TorqueEnergy, newton_metres }
+
+// Display the ambiguous unit, to make it clear the value still needs to be
+// converted to either Torque or Energy.
+implement_display! { TorqueEnergy }
+
+// Keep sums of `Force * Length` products as a `TorqueEnergy`, so they can be
+// converted once at the end.
+implement_arithmetic! { TorqueEnergy }
+
+impl ::std::cmp::Eq for TorqueEnergy {}
+impl ::std::cmp::PartialEq for TorqueEnergy {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_base_units() == other.as_base_units()
+    }
+}
+
+impl ::std::cmp::PartialOrd for TorqueEnergy {
+    fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        self.as_base_units().partial_cmp(&other.as_base_units())
+    }
 }
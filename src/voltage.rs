@@ -1,6 +1,16 @@
 //! Types and constants for handling voltage.
 
 use super::measurement::*;
+use resistance::Resistance;
+
+/// Units supported by [`Voltage`], as (symbol, long name, number of volts
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("\u{00B5}V", "microvolts", 1e-6),
+    ("mV", "millivolts", 1e-3),
+    ("V", "volts", 1.0),
+    ("kV", "kilovolts", 1e3),
+];
 
 /// The `Voltage` struct can be used to deal with electric potential difference
 /// in a common way.
@@ -16,7 +26,8 @@ use super::measurement::*;
 /// println!("A 1.5 V battery has {} mV or {} kV", m_v, k_v);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Voltage {
     volts: f64,
 }
@@ -61,9 +72,24 @@ impl Voltage {
     pub fn as_kilovolts(&self) -> f64 {
         self.volts / 1000.0
     }
+
+    /// Calculate the output of a voltage divider with this Voltage across
+    /// `r_top` and `r_bottom` in series, measured across `r_bottom`
+    /// (V × R_bottom / (R_top + R_bottom)). If both resistances are zero, the
+    /// output is zero rather than NaN.
+    pub fn divided(&self, r_top: Resistance, r_bottom: Resistance) -> Voltage {
+        let total = r_top.as_ohms() + r_bottom.as_ohms();
+        if total == 0.0 {
+            Voltage::from_volts(0.0)
+        } else {
+            Voltage::from_volts(self.volts * r_bottom.as_ohms() / total)
+        }
+    }
 }
 
 impl Measurement for Voltage {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.volts
     }
@@ -94,8 +120,14 @@ impl Measurement for Voltage {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Voltage"
+    }
 }
 
+implement_debug! { Voltage, volts }
+
 implement_measurement! { Voltage }
 
 #[cfg(test)]
@@ -129,6 +161,23 @@ mod test {
         assert_almost_eq(u.as_millivolts(), 1234.0);
     }
 
+    #[test]
+    fn divided() {
+        let v = Voltage::from_volts(5.0);
+        let out = v.divided(
+            Resistance::from_kiloohms(1.0),
+            Resistance::from_kiloohms(1.0),
+        );
+        assert_almost_eq(out.as_volts(), 2.5);
+        let out = v.divided(
+            Resistance::from_kiloohms(10.0),
+            Resistance::from_kiloohms(3.3),
+        );
+        assert_almost_eq(out.as_volts(), 5.0 * 3.3 / 13.3);
+        let out = v.divided(Resistance::from_ohms(0.0), Resistance::from_ohms(0.0));
+        assert_eq!(out.as_volts(), 0.0);
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -1,10 +1,55 @@
 //! Types and constants for handling volumes (that is, three-dimensional space, not loudness).
 
 use super::measurement::*;
+use acceleration::Acceleration;
+use density::{Density, WATER_KGCM};
+use force::Force;
+use length::Length;
+use mass::Mass;
 #[cfg(feature = "from_str")]
-use regex::Regex;
+use parse::{parse_number, split_number_unit, unknown_unit, ParseMeasurementError};
 #[cfg(feature = "from_str")]
 use std::str::FromStr;
+use temperature::TemperatureDelta;
+use time;
+use volumetric_flow_rate::VolumetricFlowRate;
+use PI;
+
+/// Units supported by [`Volume`], as (symbol, long name, number of liters
+/// in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    (
+        "cm\u{00B3}",
+        "cubic centimeters",
+        1.0 / LITER_CUBIC_CENTIMETER_FACTOR,
+    ),
+    ("ml", "milliliters", 1.0 / LITER_MILLILITERS_FACTOR),
+    ("l", "liters", 1.0),
+    ("m\u{00B3}", "cubic meters", 1.0 / LITER_CUBIC_METER_FACTOR),
+    ("gtt", "drops", 1.0 / LITER_DROP_FACTOR),
+    ("dr", "drams", 1.0 / LITER_DRAM_FACTOR),
+    ("tsp", "teaspoons", 1.0 / LITER_TEASPOONS_FACTOR),
+    ("tbsp", "tablespoons", 1.0 / LITER_TABLESPOONS_FACTOR),
+    (
+        "in\u{00B3}",
+        "cubic inches",
+        1.0 / LITER_CUBIC_INCHES_FACTOR,
+    ),
+    (
+        "imp fl oz",
+        "imperial fluid ounces",
+        1.0 / LITER_FLUID_OUNCES_UK_FACTOR,
+    ),
+    ("fl oz", "fluid ounces", 1.0 / LITER_FLUID_OUNCES_FACTOR),
+    ("cup", "cups", 1.0 / LITER_CUP_FACTOR),
+    ("pt", "pints", 1.0 / LITER_PINTS_FACTOR),
+    ("imp pt", "imperial pints", 1.0 / LITER_PINTS_UK_FACTOR),
+    ("qt", "quarts", 1.0 / LITER_QUARTS_FACTOR),
+    ("gal", "gallons", 1.0 / LITER_GALLONS_FACTOR),
+    ("imp gal", "imperial gallons", 1.0 / LITER_GALLONS_UK_FACTOR),
+    ("ft\u{00B3}", "cubic feet", 1.0 / LITER_CUBIC_FEET_FACTOR),
+    ("yd\u{00B3}", "cubic yards", 1.0 / LITER_CUBIC_YARD_FACTOR),
+];
 
 /// The `Volume` struct can be used to deal with volumes in a common way.
 ///
@@ -19,7 +64,8 @@ use std::str::FromStr;
 /// println!("A gallon of beer will pour {} pints!", beers);
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
 pub struct Volume {
     liters: f64,
 }
@@ -152,6 +198,26 @@ impl Volume {
         Self::from_liters(pints_uk / LITER_PINTS_UK_FACTOR)
     }
 
+    /// Create a new Volume from a floating point value in US Fluid Ounces (29.57 ml)
+    pub fn from_us_fluid_ounces(fluid_ounces: f64) -> Self {
+        Self::from_fluid_ounces(fluid_ounces)
+    }
+
+    /// Create a new Volume from a floating point value in Imperial (UK) Fluid Ounces (28.41 ml)
+    pub fn from_imperial_fluid_ounces(fluid_ounces: f64) -> Self {
+        Self::from_fluid_ounces_uk(fluid_ounces)
+    }
+
+    /// Create a new Volume from a floating point value in US Pints (473.18 ml)
+    pub fn from_us_pints(pints: f64) -> Self {
+        Self::from_pints(pints)
+    }
+
+    /// Create a new Volume from a floating point value in Imperial (UK) Pints (568.26 ml)
+    pub fn from_imperial_pints(pints: f64) -> Self {
+        Self::from_pints_uk(pints)
+    }
+
     /// Create a new Volume from a floating point value in Quarts
     pub fn from_quarts(quarts: f64) -> Self {
         Self::from_liters(quarts / LITER_QUARTS_FACTOR)
@@ -177,6 +243,43 @@ impl Volume {
         Self::from_liters(cubic_yards / LITER_CUBIC_YARD_FACTOR)
     }
 
+    /// Create a new Volume from the mass of water that fills it (at 1000 kg/m³)
+    pub fn of_water(mass: Mass) -> Self {
+        mass / Density::from_kilograms_per_cubic_meter(WATER_KGCM)
+    }
+
+    /// Create a new Volume of a box (cuboid) with the given length, width
+    /// and height
+    pub fn from_dimensions(length: Length, width: Length, height: Length) -> Self {
+        Self::from_cubic_meters(length.as_meters() * width.as_meters() * height.as_meters())
+    }
+
+    /// Create a new Volume of a cylinder with the given radius and height (πr²h)
+    pub fn of_cylinder(radius: Length, height: Length) -> Self {
+        let r = radius.as_meters();
+        Self::from_cubic_meters(PI * r * r * height.as_meters())
+    }
+
+    /// Create a new Volume of a sphere with the given radius (4/3 πr³)
+    pub fn of_sphere(radius: Length) -> Self {
+        let r = radius.as_meters();
+        Self::from_cubic_meters(4.0 / 3.0 * PI * r * r * r)
+    }
+
+    /// Calculate the edge Length of a cube with this Volume (∛V), e.g. as a
+    /// characteristic dimension of a container
+    #[cfg(feature = "std")]
+    pub fn cube_root_length(&self) -> Length {
+        Length::from_meters(self.as_cubic_meters().cbrt())
+    }
+
+    /// Calculate the edge Length of a cube with this Volume (∛V), e.g. as a
+    /// characteristic dimension of a container
+    #[cfg(not(feature = "std"))]
+    pub fn cube_root_length(&self) -> Length {
+        Length::from_meters(libm::cbrt(self.as_cubic_meters()))
+    }
+
     /// Convert Volume to a floating point value in Cubic Centimeters (cc or cm³)
     pub fn as_cubic_centimeters(&self) -> f64 {
         self.liters * LITER_CUBIC_CENTIMETER_FACTOR
@@ -267,6 +370,26 @@ impl Volume {
         self.liters * LITER_PINTS_UK_FACTOR
     }
 
+    /// Convert Volume to a floating point value in US Fluid Ounces (29.57 ml)
+    pub fn as_us_fluid_ounces(&self) -> f64 {
+        self.as_fluid_ounces()
+    }
+
+    /// Convert Volume to a floating point value in Imperial (UK) Fluid Ounces (28.41 ml)
+    pub fn as_imperial_fluid_ounces(&self) -> f64 {
+        self.as_fluid_ounces_uk()
+    }
+
+    /// Convert Volume to a floating point value in US Pints (473.18 ml)
+    pub fn as_us_pints(&self) -> f64 {
+        self.as_pints()
+    }
+
+    /// Convert Volume to a floating point value in Imperial (UK) Pints (568.26 ml)
+    pub fn as_imperial_pints(&self) -> f64 {
+        self.as_pints_uk()
+    }
+
     /// Convert Volume to a floating point value in Quarts
     pub fn as_quarts(&self) -> f64 {
         self.liters * LITER_QUARTS_FACTOR
@@ -291,9 +414,36 @@ impl Volume {
     pub fn as_cubic_yards(&self) -> f64 {
         self.liters * LITER_CUBIC_YARD_FACTOR
     }
+
+    /// Calculate this Volume after a change in temperature, given a volumetric
+    /// thermal expansion coefficient: V × (1 + β × ΔT). For water at room
+    /// temperature, `coefficient_per_kelvin` is about 0.00021.
+    pub fn thermal_expanded(&self, delta: TemperatureDelta, coefficient_per_kelvin: f64) -> Self {
+        Self::from_liters(self.liters * (1.0 + coefficient_per_kelvin * delta.as_kelvin()))
+    }
+
+    /// Calculate the buoyant force on a body displacing this Volume of a
+    /// fluid with the given density, under the given acceleration due to
+    /// gravity (Archimedes' principle: ρ × V × g)
+    pub fn buoyant_force(&self, fluid_density: Density, gravity: Acceleration) -> Force {
+        (fluid_density * *self) * gravity
+    }
+
+    /// Calculate the time it takes to fill this Volume at the given
+    /// VolumetricFlowRate (V / Q), e.g. how long a tank takes to fill from a
+    /// pump. Returns `None` if the rate is zero or negative.
+    pub fn fill_time(&self, rate: VolumetricFlowRate) -> Option<time::Duration> {
+        if rate.as_cubic_meters_per_second() > 0.0 {
+            Some(*self / rate)
+        } else {
+            None
+        }
+    }
 }
 
 impl Measurement for Volume {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
     fn as_base_units(&self) -> f64 {
         self.liters
     }
@@ -319,11 +469,15 @@ impl Measurement for Volume {
         ];
         self.pick_appropriate_units(&list)
     }
+
+    fn quantity_name() -> &'static str {
+        "Volume"
+    }
 }
 
 #[cfg(feature = "from_str")]
 impl FromStr for Volume {
-    type Err = std::num::ParseFloatError;
+    type Err = ParseMeasurementError;
 
     /// Create a new Volume from a string
     /// Plain numbers in string are considered to be Liters. Defaults for units with US
@@ -333,41 +487,35 @@ impl FromStr for Volume {
             return Ok(Volume::from_liters(0.0));
         }
 
-        let re = Regex::new(r"(?i)\s*([0-9.]*)\s?([a-z .]{1,10}[0-9³]{0,1})\s*$").unwrap();
-        if let Some(caps) = re.captures(val) {
-            let float_val = caps.get(1).unwrap().as_str();
-            return Ok(
-                match caps.get(2).unwrap().as_str().to_lowercase().as_str() {
-                    "cm3" | "cm\u{00b3}" => {
-                        Volume::from_cubic_centimeters(float_val.parse::<f64>()?)
-                    }
-                    "ft3" | "ft\u{00b3}" => Volume::from_cubic_feet(float_val.parse::<f64>()?),
-                    "yd3" | "yd\u{00b3}" => Volume::from_cubic_yards(float_val.parse::<f64>()?),
-                    "in3" | "in\u{00b3}" => Volume::from_cubic_inches(float_val.parse::<f64>()?),
-                    "gal" | "us gal" => Volume::from_gallons(float_val.parse::<f64>()?),
-                    "imp gal" => Volume::from_gallons_uk(float_val.parse::<f64>()?),
-                    "cup" => Volume::from_cups(float_val.parse::<f64>()?),
-                    "tsp" => Volume::from_teaspoons(float_val.parse::<f64>()?),
-                    "tbsp" | "t." => Volume::from_tablespoons(float_val.parse::<f64>()?),
-                    "ml" => Volume::from_milliliters(float_val.parse::<f64>()?),
-                    "us fl oz" | "fl oz" => Volume::from_fluid_ounces(float_val.parse::<f64>()?),
-                    "imp fl oz" => Volume::from_fluid_ounces_uk(float_val.parse::<f64>()?),
-                    "m3" | "m\u{00b3}" => Volume::from_cubic_meters(float_val.parse::<f64>()?),
-                    "gt" | "gtt" => Volume::from_drops(float_val.parse::<f64>()?),
-                    "dr" => Volume::from_drams(float_val.parse::<f64>()?),
-                    "l" => Volume::from_litres(float_val.parse::<f64>()?),
-                    "qt" => Volume::from_quarts(float_val.parse::<f64>()?),
-                    "us pt" | "us p" | "p" | "pt" => Volume::from_pints(float_val.parse::<f64>()?),
-                    "imp pt" | "imp p" => Volume::from_pints_uk(float_val.parse::<f64>()?),
-                    _ => Volume::from_litres(val.parse::<f64>()?),
-                },
-            );
-        }
-
-        Ok(Volume::from_liters(val.parse::<f64>()?))
+        let (number, unit) = split_number_unit(val);
+        Ok(match unit.to_lowercase().as_str() {
+            "" => Volume::from_liters(parse_number(number)?),
+            "cm3" | "cm\u{00b3}" => Volume::from_cubic_centimeters(parse_number(number)?),
+            "ft3" | "ft\u{00b3}" => Volume::from_cubic_feet(parse_number(number)?),
+            "yd3" | "yd\u{00b3}" => Volume::from_cubic_yards(parse_number(number)?),
+            "in3" | "in\u{00b3}" => Volume::from_cubic_inches(parse_number(number)?),
+            "gal" | "us gal" => Volume::from_gallons(parse_number(number)?),
+            "imp gal" => Volume::from_gallons_uk(parse_number(number)?),
+            "cup" => Volume::from_cups(parse_number(number)?),
+            "tsp" => Volume::from_teaspoons(parse_number(number)?),
+            "tbsp" | "t." => Volume::from_tablespoons(parse_number(number)?),
+            "ml" => Volume::from_milliliters(parse_number(number)?),
+            "us fl oz" | "fl oz" => Volume::from_fluid_ounces(parse_number(number)?),
+            "imp fl oz" => Volume::from_fluid_ounces_uk(parse_number(number)?),
+            "m3" | "m\u{00b3}" => Volume::from_cubic_meters(parse_number(number)?),
+            "gt" | "gtt" => Volume::from_drops(parse_number(number)?),
+            "dr" => Volume::from_drams(parse_number(number)?),
+            "l" => Volume::from_litres(parse_number(number)?),
+            "qt" => Volume::from_quarts(parse_number(number)?),
+            "us pt" | "us p" | "p" | "pt" => Volume::from_pints(parse_number(number)?),
+            "imp pt" | "imp p" => Volume::from_pints_uk(parse_number(number)?),
+            _ => return Err(unknown_unit(&unit)),
+        })
     }
 }
 
+implement_debug! { Volume, liters }
+
 implement_measurement! { Volume }
 
 #[cfg(test)]
@@ -583,6 +731,105 @@ mod test {
         assert_almost_eq(o, 76455.4857992);
     }
 
+    #[test]
+    fn of_water() {
+        let v = Volume::of_water(Mass::from_kilograms(1.0));
+        assert_almost_eq(v.as_liters(), 1.0);
+    }
+
+    #[test]
+    fn us_and_imperial_pints() {
+        let us = Volume::from_us_pints(1.0);
+        let imperial = Volume::from_imperial_pints(1.0);
+        assert_almost_eq(us.as_milliliters(), 473.176473);
+        assert_almost_eq(imperial.as_milliliters(), 568.26125);
+        assert_almost_eq((imperial - us).as_milliliters(), 95.084777);
+        assert_almost_eq(imperial.as_us_pints(), 1.200950);
+        assert_almost_eq(us.as_imperial_pints(), 0.832674);
+    }
+
+    #[test]
+    fn us_and_imperial_fluid_ounces() {
+        let us = Volume::from_us_fluid_ounces(1.0);
+        let imperial = Volume::from_imperial_fluid_ounces(1.0);
+        assert_almost_eq(us.as_milliliters(), 29.573530);
+        assert_almost_eq(imperial.as_milliliters(), 28.413063);
+        assert_almost_eq(imperial.as_us_fluid_ounces(), 0.960760);
+        assert_almost_eq(us.as_imperial_fluid_ounces(), 1.040843);
+        // 20 imperial fluid ounces to the imperial pint, but 16 US fluid ounces to the US pint
+        assert_almost_eq(
+            Volume::from_imperial_pints(1.0).as_imperial_fluid_ounces(),
+            20.0,
+        );
+        assert_almost_eq(Volume::from_us_pints(1.0).as_us_fluid_ounces(), 16.0);
+    }
+
+    #[test]
+    fn thermal_expanded() {
+        let tank = Volume::from_liters(1000.0);
+        let warmer = tank.thermal_expanded(TemperatureDelta::from_kelvin(20.0), 0.00021);
+        assert_almost_eq(warmer.as_liters(), 1004.2);
+        let colder = tank.thermal_expanded(TemperatureDelta::from_kelvin(-20.0), 0.00021);
+        assert_almost_eq(colder.as_liters(), 995.8);
+    }
+
+    #[test]
+    fn from_dimensions() {
+        let one = Length::from_meters(1.0);
+        assert_almost_eq(
+            Volume::from_dimensions(one, one, one).as_cubic_meters(),
+            1.0,
+        );
+        let v = Volume::from_dimensions(
+            Length::from_centimeters(10.0),
+            Length::from_centimeters(20.0),
+            Length::from_centimeters(30.0),
+        );
+        assert_almost_eq(v.as_liters(), 6.0);
+    }
+
+    #[test]
+    fn of_cylinder() {
+        let v = Volume::of_cylinder(Length::from_meters(1.0), Length::from_meters(2.0));
+        assert_almost_eq(v.as_cubic_meters(), 2.0 * PI);
+    }
+
+    #[test]
+    fn of_sphere() {
+        let v = Volume::of_sphere(Length::from_meters(1.0));
+        assert_almost_eq(v.as_cubic_meters(), 4.188790);
+    }
+
+    #[test]
+    fn cube_root_length() {
+        let v = Volume::from_cubic_meters(27.0);
+        assert_almost_eq(v.cube_root_length().as_meters(), 3.0);
+        assert_almost_eq(
+            Volume::from_liters(1.0).cube_root_length().as_centimeters(),
+            10.0,
+        );
+    }
+
+    #[test]
+    fn buoyant_force() {
+        let water = Density::from_kilograms_per_cubic_meter(1000.0);
+        let f =
+            Volume::from_cubic_meters(1.0).buoyant_force(water, ::constants::standard_gravity());
+        assert_almost_eq(f.as_newtons(), 9806.65);
+    }
+
+    #[test]
+    fn fill_time() {
+        let tank = Volume::from_liters(1000.0);
+        let rate = VolumetricFlowRate::from_liters_per_minute(10.0);
+        let t = tank.fill_time(rate).unwrap();
+        assert_almost_eq(t.as_base_units(), 100.0 * 60.0);
+        assert_eq!(
+            tank.fill_time(VolumetricFlowRate::from_liters_per_minute(0.0)),
+            None
+        );
+    }
+
     // Traits
     #[test]
     fn add() {
@@ -857,6 +1104,16 @@ mod test {
         assert_almost_eq(10.0, v2.unwrap().as_pints_uk());
     }
 
+    #[test]
+    #[cfg(feature = "from_str")]
+    fn unit_symbols_from_str() {
+        for &(symbol, _, _) in UNITS {
+            let v = Volume::from_str(&format!("10 {}", symbol));
+            assert!(v.is_ok(), "{} does not parse", symbol);
+            assert_almost_eq(10.0, v.unwrap().value_in(symbol).unwrap());
+        }
+    }
+
     #[test]
     #[cfg(feature = "from_str")]
     fn default_from_str() {
@@ -0,0 +1,252 @@
+//! Types and constants for handling volumetric flow rates.
+
+use super::measurement::*;
+use time;
+use volume::Volume;
+
+/// Units supported by [`VolumetricFlowRate`], as (symbol, long name, number
+/// of cubic meters per second in one unit) triples.
+pub const UNITS: &[(&str, &str, f64)] = &[
+    ("l/min", "liters per minute", 1e-3 / 60.0),
+    ("m\u{00B3}/h", "cubic meters per hour", 1.0 / 3600.0),
+    ("l/s", "liters per second", 1e-3),
+    ("m\u{00B3}/s", "cubic meters per second", 1.0),
+];
+
+/// The `VolumetricFlowRate` struct can be used to deal with the rate at which
+/// a volume of fluid flows, such as through a pipe or pump, in a common way.
+///
+/// # Example
+///
+/// ```
+/// use measurements::{Volume, VolumetricFlowRate};
+///
+/// let tank = Volume::from_liters(1000.0);
+/// let hose = VolumetricFlowRate::from_liters_per_minute(10.0);
+/// println!("The tank fills in {:?}.", tank.fill_time(hose));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Copy, Clone, Default)]
+pub struct VolumetricFlowRate {
+    cubic_meters_per_second: f64,
+}
+
+impl VolumetricFlowRate {
+    /// Create a new VolumetricFlowRate from a floating point value in cubic meters per second
+    pub fn from_cubic_meters_per_second(cubic_meters_per_second: f64) -> Self {
+        VolumetricFlowRate {
+            cubic_meters_per_second,
+        }
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in cubic meters per hour
+    pub fn from_cubic_meters_per_hour(cubic_meters_per_hour: f64) -> Self {
+        Self::from_cubic_meters_per_second(cubic_meters_per_hour / 3600.0)
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in liters per second
+    pub fn from_liters_per_second(liters_per_second: f64) -> Self {
+        Self::from_cubic_meters_per_second(liters_per_second / 1000.0)
+    }
+
+    /// Create a new VolumetricFlowRate from a floating point value in liters per minute
+    pub fn from_liters_per_minute(liters_per_minute: f64) -> Self {
+        Self::from_cubic_meters_per_second(liters_per_minute / 60_000.0)
+    }
+
+    /// Create a new VolumetricFlowRate needed to fill the given Volume within
+    /// the given time. Returns `None` if the time is zero.
+    pub fn to_fill(volume: Volume, within: time::Duration) -> Option<Self> {
+        let seconds = within.as_base_units();
+        if seconds > 0.0 {
+            Some(Self::from_cubic_meters_per_second(
+                volume.as_cubic_meters() / seconds,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in cubic meters per second
+    pub fn as_cubic_meters_per_second(&self) -> f64 {
+        self.cubic_meters_per_second
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in cubic meters per hour
+    pub fn as_cubic_meters_per_hour(&self) -> f64 {
+        self.cubic_meters_per_second * 3600.0
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in liters per second
+    pub fn as_liters_per_second(&self) -> f64 {
+        self.cubic_meters_per_second * 1000.0
+    }
+
+    /// Convert this VolumetricFlowRate into a floating point value in liters per minute
+    pub fn as_liters_per_minute(&self) -> f64 {
+        self.cubic_meters_per_second * 60_000.0
+    }
+}
+
+// volume / duration = volumetric flow rate
+impl ::std::ops::Div<time::Duration> for Volume {
+    type Output = VolumetricFlowRate;
+
+    fn div(self, other: time::Duration) -> VolumetricFlowRate {
+        VolumetricFlowRate::from_base_units(self.as_cubic_meters() / other.as_base_units())
+    }
+}
+
+// volume / volumetric flow rate = duration
+impl ::std::ops::Div<VolumetricFlowRate> for Volume {
+    type Output = time::Duration;
+
+    fn div(self, other: VolumetricFlowRate) -> time::Duration {
+        time::Duration::from_base_units(self.as_cubic_meters() / other.as_base_units())
+    }
+}
+
+// volumetric flow rate * duration = volume
+impl ::std::ops::Mul<time::Duration> for VolumetricFlowRate {
+    type Output = Volume;
+
+    fn mul(self, other: time::Duration) -> Volume {
+        Volume::from_cubic_meters(self.as_base_units() * other.as_base_units())
+    }
+}
+
+// duration * volumetric flow rate = volume
+impl ::std::ops::Mul<VolumetricFlowRate> for time::Duration {
+    type Output = Volume;
+
+    fn mul(self, other: VolumetricFlowRate) -> Volume {
+        Volume::from_cubic_meters(self.as_base_units() * other.as_base_units())
+    }
+}
+
+impl Measurement for VolumetricFlowRate {
+    const UNITS: &'static [(&'static str, &'static str, f64)] = UNITS;
+
+    fn as_base_units(&self) -> f64 {
+        self.cubic_meters_per_second
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Self::from_cubic_meters_per_second(units)
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "m\u{00B3}/s"
+    }
+
+    fn get_appropriate_units(&self) -> (&'static str, f64) {
+        // Smallest to Largest
+        let list = [("ml/s", 1e-6), ("l/s", 1e-3), ("m\u{00B3}/s", 1e0)];
+        self.pick_appropriate_units(&list)
+    }
+
+    fn quantity_name() -> &'static str {
+        "VolumetricFlowRate"
+    }
+}
+
+implement_debug! { VolumetricFlowRate, cubic_meters_per_second }
+
+implement_measurement! { VolumetricFlowRate }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn liters_per_minute() {
+        let i1 = VolumetricFlowRate::from_liters_per_minute(60.0);
+        let r1 = i1.as_liters_per_second();
+        let i2 = VolumetricFlowRate::from_cubic_meters_per_hour(3.6);
+        let r2 = i2.as_liters_per_second();
+        assert_almost_eq(r1, 1.0);
+        assert_almost_eq(r2, 1.0);
+    }
+
+    #[test]
+    fn to_fill() {
+        let tank = Volume::from_liters(1000.0);
+        let rate = VolumetricFlowRate::to_fill(tank, time::Duration::from_secs(100 * 60));
+        assert_almost_eq(rate.unwrap().as_liters_per_minute(), 10.0);
+        let none = VolumetricFlowRate::to_fill(tank, time::Duration::from_secs(0));
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn volume_over_time() {
+        let rate = VolumetricFlowRate::from_liters_per_second(2.0);
+        let v: Volume = rate * time::Duration::from_secs(30);
+        assert_almost_eq(v.as_liters(), 60.0);
+        let r: VolumetricFlowRate = v / time::Duration::from_secs(30);
+        assert_almost_eq(r.as_liters_per_second(), 2.0);
+    }
+
+    // Traits
+    #[test]
+    fn add() {
+        let a = VolumetricFlowRate::from_cubic_meters_per_second(2.0);
+        let b = VolumetricFlowRate::from_cubic_meters_per_second(4.0);
+        let c = a + b;
+        let d = b + a;
+        assert_almost_eq(c.as_cubic_meters_per_second(), 6.0);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn sub() {
+        let a = VolumetricFlowRate::from_cubic_meters_per_second(2.0);
+        let b = VolumetricFlowRate::from_cubic_meters_per_second(4.0);
+        let c = a - b;
+        assert_almost_eq(c.as_cubic_meters_per_second(), -2.0);
+    }
+
+    #[test]
+    fn mul() {
+        let a = VolumetricFlowRate::from_cubic_meters_per_second(3.0);
+        let b = a * 2.0;
+        let c = 2.0 * a;
+        assert_almost_eq(b.as_cubic_meters_per_second(), 6.0);
+        assert_eq!(b, c);
+    }
+
+    #[test]
+    fn div() {
+        let a = VolumetricFlowRate::from_cubic_meters_per_second(2.0);
+        let b = VolumetricFlowRate::from_cubic_meters_per_second(4.0);
+        let c = a / b;
+        let d = a / 2.0;
+        assert_almost_eq(c, 0.5);
+        assert_almost_eq(d.as_cubic_meters_per_second(), 1.0);
+    }
+
+    #[test]
+    fn eq() {
+        let a = VolumetricFlowRate::from_cubic_meters_per_second(2.0);
+        let b = VolumetricFlowRate::from_cubic_meters_per_second(2.0);
+        assert_eq!(a == b, true);
+    }
+
+    #[test]
+    fn neq() {
+        let a = VolumetricFlowRate::from_cubic_meters_per_second(2.0);
+        let b = VolumetricFlowRate::from_cubic_meters_per_second(4.0);
+        assert_eq!(a == b, false);
+    }
+
+    #[test]
+    fn cmp() {
+        let a = VolumetricFlowRate::from_cubic_meters_per_second(2.0);
+        let b = VolumetricFlowRate::from_cubic_meters_per_second(4.0);
+        assert_eq!(a < b, true);
+        assert_eq!(a <= b, true);
+        assert_eq!(a > b, false);
+        assert_eq!(a >= b, false);
+    }
+}
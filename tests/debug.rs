@@ -0,0 +1,25 @@
+extern crate measurements;
+
+use measurements::*;
+
+#[test]
+#[cfg(not(feature = "debug_display"))]
+fn debug_matches_derive() {
+    let p = Pressure::from_pascals(101325.0);
+    assert_eq!(format!("{:?}", p), "Pressure { pascals: 101325.0 }");
+    let t = Temperature::from_kelvin(300.0);
+    assert_eq!(format!("{:?}", t), "Temperature { degrees_kelvin: 300.0 }");
+}
+
+#[test]
+#[cfg(feature = "debug_display")]
+fn debug_includes_display() {
+    let p = Pressure::from_pascals(101325.0);
+    assert_eq!(
+        format!("{:?}", p),
+        "Pressure { pascals: 101325.0, display: \"101.325\u{00A0}kPa\" }"
+    );
+    let t = Temperature::from_kelvin(300.0);
+    let debug = format!("{:?}", t);
+    assert!(debug.contains(&format!("\"{}\"", t)));
+}
@@ -0,0 +1,51 @@
+extern crate measurements;
+
+use measurements::Measurement;
+use std::time::Duration;
+
+#[test]
+fn from_base_units_sub_microsecond() {
+    let d = Duration::from_base_units(1234.000000567);
+    assert_eq!(d.as_secs(), 1234);
+    assert_eq!(d.subsec_nanos(), 567);
+}
+
+#[test]
+fn base_units_round_trip() {
+    let d = Duration::new(1234, 567);
+    assert_eq!(Duration::from_base_units(d.as_base_units()), d);
+    let d = Duration::new(0, 1);
+    assert_eq!(Duration::from_base_units(d.as_base_units()), d);
+}
+
+#[test]
+fn from_base_units_large() {
+    let d = Duration::from_base_units(10_000_000_000.25);
+    assert_eq!(d.as_secs(), 10_000_000_000);
+    assert_eq!(d.subsec_nanos(), 250_000_000);
+    let d = Duration::from_base_units(1e30);
+    assert_eq!(d.as_secs(), u64::MAX);
+}
+
+#[test]
+fn from_base_units_carry() {
+    let d = Duration::from_base_units(0.999_999_999_9);
+    assert_eq!(d, Duration::from_secs(1));
+    let d = Duration::from_base_units(41.999_999_999_9);
+    assert_eq!(d, Duration::from_secs(42));
+}
+
+#[test]
+fn from_base_units_negative() {
+    assert_eq!(Duration::from_base_units(-5.0), Duration::from_secs(0));
+    assert_eq!(Duration::from_base_units(-0.5), Duration::from_secs(0));
+    assert_eq!(Duration::from_base_units(f64::NAN), Duration::from_secs(0));
+}
+
+#[test]
+fn value_in_display_units() {
+    let d = Duration::from_secs(90);
+    assert_eq!(d.value_in("min"), Some(1.5));
+    assert_eq!(measurements::DurationDisplay(d).value_in("s"), Some(90.0));
+    assert_eq!(d.value_in("fortnight"), None);
+}
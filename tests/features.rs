@@ -0,0 +1,58 @@
+//! Checks that the optional features work together, whichever of them are
+//! enabled. Run with each combination from the CI matrix, e.g.
+//! `cargo test --features serde,from_str`.
+
+extern crate measurements;
+#[cfg(feature = "serde")]
+extern crate serde_json;
+
+#[allow(unused_imports)]
+use measurements::*;
+
+#[test]
+#[cfg(all(feature = "serde", feature = "from_str"))]
+fn parse_then_serialize() {
+    use std::str::FromStr;
+    let t = Temperature::from_str("25 C").unwrap();
+    let json = serde_json::to_string(&t).unwrap();
+    let back: Temperature = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, t);
+    let m = Mass::from_str("2 kg").unwrap();
+    assert_eq!(serde_json::to_string(&m).unwrap(), "2.0");
+}
+
+#[test]
+#[cfg(all(feature = "serde", not(feature = "std")))]
+fn serde_without_std() {
+    let d = TemperatureDelta::from_kelvin(3.0);
+    let back: TemperatureDelta = serde_json::from_str(&serde_json::to_string(&d).unwrap()).unwrap();
+    assert_eq!(back, d);
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "typed_ratio"))]
+fn serde_with_typed_ratio() {
+    let ratio = Length::from_meters(3.0) / Length::from_meters(4.0);
+    assert_eq!(ratio.as_ratio(), 0.75);
+    let json = serde_json::to_string(&ratio).unwrap();
+    let back: Ratio = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, ratio);
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "debug_display"))]
+fn serde_with_debug_display() {
+    let p: Pressure = serde_json::from_str("101325").unwrap();
+    let debug = format!("{:?}", p);
+    assert!(debug.contains("pascals: 101325.0"));
+    assert!(debug.contains("101.325"));
+    assert!(debug.contains("kPa"));
+}
+
+#[test]
+#[cfg(all(feature = "from_str", feature = "sig_fig_display"))]
+fn parse_then_display_significant_figures() {
+    use std::str::FromStr;
+    let m = Mass::from_str("0.73756326522588 kg").unwrap();
+    assert_eq!(format!("{}", m), "737.563\u{00A0}g");
+}
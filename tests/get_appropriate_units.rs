@@ -1,6 +1,9 @@
 extern crate measurements;
 
-use measurements::{mass::Mass, test_utils::assert_almost_eq, Measurement};
+use measurements::{
+    current::Current, data::Data, frequency::Frequency, length::Length, mass::Mass,
+    test_utils::assert_almost_eq, Measurement,
+};
 
 // Macro for testing `get_appropriate_units()`.
 // Specify the name of the test, the initial value (in kg) to be
@@ -50,3 +53,113 @@ test_from_kg!(
     "g",
     1000.0
 );
+
+#[test]
+fn zero_kg_keeps_unit() {
+    let mass = Mass::from_kilograms(0.0);
+    let (unit, v) = mass.get_appropriate_units();
+    assert_eq!(unit, "kg");
+    assert_eq!(v, 0.0);
+}
+
+#[test]
+fn negative_frequency_uses_magnitude() {
+    let f = Frequency::from_gigahertz(-1.5);
+    let (unit, v) = f.get_appropriate_units();
+    assert_eq!(unit, "GHz");
+    assert_eq!(v, -1.5);
+}
+
+#[test]
+fn zero_frequency_is_hertz() {
+    let f = Frequency::from_hertz(0.0);
+    let (unit, v) = f.get_appropriate_units();
+    assert_eq!(unit, "Hz");
+    assert_eq!(v, 0.0);
+}
+
+#[test]
+fn negative_data_uses_magnitude() {
+    let d = Data::from_kibioctets(-2.0);
+    let (unit, v) = d.get_appropriate_units();
+    assert_eq!(unit, "KiB");
+    assert_eq!(v, -2.0);
+}
+
+#[test]
+fn zero_data_is_octets() {
+    let d = Data::from_octets(0.0);
+    let (unit, v) = d.get_appropriate_units();
+    assert_eq!(unit, "octets");
+    assert_eq!(v, 0.0);
+}
+
+#[test]
+fn negative_current_uses_magnitude() {
+    let c = Current::from_milliamperes(-20.0);
+    let (unit, v) = c.get_appropriate_units();
+    assert_eq!(unit, "mA");
+    assert_almost_eq(v, -20.0);
+}
+
+#[test]
+fn zero_current_is_amperes() {
+    let c = Current::from_amperes(0.0);
+    let (unit, v) = c.get_appropriate_units();
+    assert_eq!(unit, "A");
+    assert_eq!(v, 0.0);
+}
+
+#[test]
+fn zero_rounded_is_base_unit() {
+    let length = Length::from_meters(0.0);
+    let (unit, v) = length.pick_appropriate_units_rounded(&LENGTH_UNITS, 4);
+    assert_eq!(unit, "m");
+    assert_eq!(v, 0.0);
+}
+
+const LENGTH_UNITS: [(&str, f64); 3] = [("mm", 1e-3), ("m", 1e0), ("km", 1e3)];
+
+#[test]
+fn rounding_up_changes_unit() {
+    let length = Length::from_meters(999.95);
+    let (unit, v) = length.pick_appropriate_units_rounded(&LENGTH_UNITS, 4);
+    assert_eq!(unit, "km");
+    assert_almost_eq(v, 1.0);
+}
+
+#[test]
+fn rounding_down_keeps_unit() {
+    let length = Length::from_meters(999.94);
+    let (unit, v) = length.pick_appropriate_units_rounded(&LENGTH_UNITS, 4);
+    assert_eq!(unit, "m");
+    assert_almost_eq(v, 999.9);
+}
+
+#[test]
+fn more_figures_keeps_unit() {
+    let length = Length::from_meters(999.95);
+    let (unit, v) = length.pick_appropriate_units_rounded(&LENGTH_UNITS, 5);
+    assert_eq!(unit, "m");
+    assert_almost_eq(v, 999.95);
+}
+
+#[test]
+fn rounding_negative_changes_unit() {
+    let length = Length::from_meters(-999.95);
+    let (unit, v) = length.pick_appropriate_units_rounded(&LENGTH_UNITS, 4);
+    assert_eq!(unit, "km");
+    assert_almost_eq(v, -1.0);
+}
+
+#[test]
+fn duration_display() {
+    use measurements::DurationDisplay;
+    use std::time::Duration;
+    let d = DurationDisplay(Duration::from_secs(90));
+    assert_eq!(format!("{}", d), "1.5\u{00A0}min");
+    let d = DurationDisplay(Duration::from_secs(3600));
+    assert_eq!(format!("{}", d), "1\u{00A0}h");
+    let d = DurationDisplay(Duration::from_micros(250));
+    assert_eq!(format!("{:.0}", d), "250\u{00A0}\u{00B5}s");
+}
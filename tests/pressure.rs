@@ -2,7 +2,7 @@ extern crate measurements;
 use measurements::prelude::*;
 
 #[test]
-fn psi() -> () {
+fn psi() {
     let p1 = measurements::Pressure::from_psi(200.0);
     let f = measurements::Force::from_pounds(200.0);
     let d = measurements::Length::from_inches(1.0);
@@ -12,7 +12,7 @@ fn psi() -> () {
 }
 
 #[test]
-fn metric() -> () {
+fn metric() {
     let p1 = measurements::Pressure::from_pascals(980.665);
     let m = measurements::Mass::from_kilograms(1.0);
     let g = measurements::Acceleration::from_meters_per_second_per_second(9.80665);
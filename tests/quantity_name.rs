@@ -0,0 +1,41 @@
+extern crate measurements;
+
+use measurements::{DurationDisplay, Measurement, Pressure, Temperature, TemperatureDelta};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+#[test]
+fn quantity_names() {
+    assert_eq!(Pressure::quantity_name(), "Pressure");
+    assert_eq!(Temperature::quantity_name(), "Temperature");
+    assert_eq!(TemperatureDelta::quantity_name(), "TemperatureDelta");
+    assert_eq!(Duration::quantity_name(), "Duration");
+    assert_eq!(DurationDisplay::quantity_name(), "Duration");
+}
+
+struct Tagged<T> {
+    value: f64,
+    tag: PhantomData<T>,
+}
+
+impl<T> Measurement for Tagged<T> {
+    fn as_base_units(&self) -> f64 {
+        self.value
+    }
+
+    fn from_base_units(units: f64) -> Self {
+        Tagged {
+            value: units,
+            tag: PhantomData,
+        }
+    }
+
+    fn get_base_units_name(&self) -> &'static str {
+        "u"
+    }
+}
+
+#[test]
+fn default_quantity_name_drops_generics() {
+    assert_eq!(Tagged::<Pressure>::quantity_name(), "Tagged");
+}
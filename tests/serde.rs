@@ -0,0 +1,66 @@
+#![cfg(feature = "serde")]
+
+extern crate measurements;
+extern crate serde_json;
+
+use measurements::*;
+
+#[test]
+fn serialize_as_base_units() {
+    let p = Pressure::from_kilopascals(101.325);
+    assert_eq!(serde_json::to_string(&p).unwrap(), "101325.0");
+    let l = Length::from_kilometers(1.5);
+    assert_eq!(serde_json::to_string(&l).unwrap(), "1500.0");
+}
+
+#[test]
+fn deserialize_base_units() {
+    let p: Pressure = serde_json::from_str("101325").unwrap();
+    assert_eq!(p, Pressure::from_pascals(101325.0));
+    let t: Temperature = serde_json::from_str("273.15").unwrap();
+    assert_eq!(t, Temperature::from_kelvin(273.15));
+}
+
+#[test]
+fn round_trip() {
+    let v = Volume::from_gallons(3.0);
+    let json = serde_json::to_string(&v).unwrap();
+    let back: Volume = serde_json::from_str(&json).unwrap();
+    assert_eq!(v, back);
+}
+
+#[test]
+fn in_a_struct() {
+    let json = r#"{"mass": 2.5, "height": 1.8}"#;
+    let value: serde_json::Value = serde_json::from_str(json).unwrap();
+    let mass: Mass = serde_json::from_value(value["mass"].clone()).unwrap();
+    let height: Length = serde_json::from_value(value["height"].clone()).unwrap();
+    assert_eq!(mass.as_kilograms(), 2.5);
+    assert_eq!(height.as_meters(), 1.8);
+}
+
+#[test]
+fn old_struct_form_is_rejected() {
+    let p: Result<Pressure, _> = serde_json::from_str(r#"{"pascals": 101325.0}"#);
+    assert!(p.is_err());
+}
+
+#[test]
+fn temperature_round_trip() {
+    let t = Temperature::from_celsius(21.5);
+    let json = serde_json::to_string(&t).unwrap();
+    let back: Temperature = serde_json::from_str(&json).unwrap();
+    assert_eq!(t, back);
+    assert!(back > Temperature::from_celsius(21.0));
+    assert!(back < Temperature::from_celsius(22.0));
+}
+
+#[test]
+fn temperature_delta_round_trip() {
+    let d = TemperatureDelta::from_fahrenheit(-9.0);
+    let json = serde_json::to_string(&d).unwrap();
+    assert_eq!(json, serde_json::to_string(&d.as_kelvin()).unwrap());
+    let back: TemperatureDelta = serde_json::from_str(&json).unwrap();
+    assert_eq!(d, back);
+    assert!(back < TemperatureDelta::from_kelvin(0.0));
+}
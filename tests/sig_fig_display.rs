@@ -0,0 +1,55 @@
+extern crate measurements;
+
+use measurements::*;
+
+#[test]
+#[cfg(not(feature = "sig_fig_display"))]
+fn display_shows_every_digit() {
+    let l = Length::from_meters(0.737_563_265_225_88);
+    assert_eq!(format!("{}", l), "73.756326522588\u{00A0}cm");
+}
+
+#[test]
+#[cfg(feature = "sig_fig_display")]
+fn display_rounds_to_significant_figures() {
+    let l = Length::from_meters(0.737_563_265_225_88);
+    assert_eq!(format!("{}", l), "73.7563\u{00A0}cm");
+    let d = Volume::from_liters(1.06) - Volume::from_liters(0.000_000_000_000_000_2);
+    assert_eq!(format!("{}", d), "1.06\u{00A0}l");
+    assert_eq!(format!("{}", Mass::from_grams(1500.0)), "1.5\u{00A0}kg");
+}
+
+#[test]
+#[cfg(feature = "sig_fig_display")]
+fn precision_is_kept() {
+    let l = Length::from_meters(0.737_563_265_225_88);
+    assert_eq!(format!("{:.9}", l), "73.756326523\u{00A0}cm");
+    assert_eq!(format!("{:.1}", l), "73.8\u{00A0}cm");
+}
+
+#[test]
+#[cfg(feature = "sig_fig_display")]
+fn tiny_values_are_not_nan() {
+    let l = Length::from_meters(1e-320);
+    assert!(!format!("{}", l).contains("NaN"));
+}
+
+#[test]
+#[cfg(feature = "sig_fig_display")]
+fn rounds_before_choosing_unit() {
+    let l = Length::from_meters(999.999_999_9);
+    assert_eq!(format!("{}", l), "1\u{00A0}km");
+    let mut buf = String::new();
+    l.write_to(&mut buf).unwrap();
+    assert_eq!(buf, "1\u{00A0}km");
+    assert_eq!(format!("{:.3}", l), "1000.000\u{00A0}m");
+}
+
+#[test]
+#[cfg(all(feature = "sig_fig_display", feature = "std"))]
+fn grouped_display_rounds() {
+    let l = Length::from_meters(0.737_563_265_225_88);
+    assert_eq!(format!("{}", l.display_grouped()), "73.7563\u{00A0}cm");
+    let l = Length::from_meters(999.999_999_9);
+    assert_eq!(format!("{}", l.display_grouped()), "1\u{00A0}km");
+}
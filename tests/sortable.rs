@@ -0,0 +1,47 @@
+extern crate measurements;
+
+use measurements::{Length, SortableMeasurement};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+#[test]
+fn btree_set_is_sorted() {
+    let mut set = BTreeSet::new();
+    set.insert(SortableMeasurement::from(Length::from_kilometers(1.0)));
+    set.insert(SortableMeasurement::from(Length::from_millimeters(1.0)));
+    set.insert(SortableMeasurement::from(Length::from_feet(1.0)));
+    set.insert(SortableMeasurement::from(Length::from_meters(-2.0)));
+    set.insert(SortableMeasurement::from(Length::from_meters(1000.0)));
+    let sorted: Vec<Length> = set.into_iter().map(|s| s.into_inner()).collect();
+    assert_eq!(
+        sorted,
+        vec![
+            Length::from_meters(-2.0),
+            Length::from_millimeters(1.0),
+            Length::from_feet(1.0),
+            Length::from_kilometers(1.0),
+        ]
+    );
+}
+
+#[test]
+fn btree_map_key() {
+    let mut map = BTreeMap::new();
+    map.insert(SortableMeasurement::new(Length::from_meters(10.0)), "ten");
+    map.insert(SortableMeasurement::new(Length::from_meters(1.0)), "one");
+    let key = SortableMeasurement::new(Length::from_centimeters(100.0));
+    assert_eq!(map.get(&key), Some(&"one"));
+    assert_eq!(
+        map.values().cloned().collect::<Vec<_>>(),
+        vec!["one", "ten"]
+    );
+}
+
+#[test]
+fn hash_set_key() {
+    let mut set = HashSet::new();
+    set.insert(SortableMeasurement::new(Length::from_meters(0.0)));
+    set.insert(SortableMeasurement::new(Length::from_meters(-0.0)));
+    set.insert(SortableMeasurement::new(Length::from_meters(f64::NAN)));
+    set.insert(SortableMeasurement::new(Length::from_meters(-f64::NAN)));
+    assert_eq!(set.len(), 2);
+}
@@ -3,7 +3,7 @@ extern crate measurements;
 use measurements::*;
 
 #[test]
-fn create() -> () {
+fn create() {
     let f = Force::from_newtons(10.0);
     let d = Length::from_metres(1.0);
     let w: Energy = Energy::from(f * d);
@@ -13,7 +13,7 @@ fn create() -> () {
 }
 
 #[test]
-fn divide() -> () {
+fn divide() {
     let w = Energy::from_joules(100.0);
     let d = Length::from_metres(10.0);
     let f: Force = w / d;
@@ -24,3 +24,57 @@ fn divide() -> () {
     let f: Force = t / d;
     test_utils::assert_almost_eq(f.as_newtons(), 10.0);
 }
+
+#[test]
+fn sum() {
+    let work = Force::from_newtons(10.0) * Length::from_metres(2.0)
+        + Force::from_newtons(5.0) * Length::from_metres(4.0)
+        + Force::from_newtons(1.0) * Length::from_metres(10.0)
+        - Force::from_newtons(2.0) * Length::from_metres(5.0);
+    let w: Energy = Energy::from(work);
+    test_utils::assert_almost_eq(w.as_joules(), 40.0);
+}
+
+#[test]
+fn scale() {
+    let t = Force::from_newtons(10.0) * Length::from_metres(2.0);
+    let t: Torque = Torque::from(2.0 * (t / 4.0));
+    test_utils::assert_almost_eq(t.as_newton_metres(), 10.0);
+}
+
+#[test]
+fn display() {
+    let t = Force::from_newtons(2.5) * Length::from_metres(2.0);
+    assert_eq!(format!("{}", t), "5\u{00A0}Nm||J");
+}
+
+#[test]
+fn copy() {
+    let te = Force::from_newtons(10.0) * Length::from_metres(2.0);
+    // Moving `te` twice only compiles because `TorqueEnergy` is `Copy`
+    let w = Energy::from(te);
+    let t = Torque::from(te);
+    test_utils::assert_almost_eq(w.as_joules(), 20.0);
+    test_utils::assert_almost_eq(t.as_newton_metres(), 20.0);
+    // ...and can still be used afterwards
+    assert!(format!("{:?}", te).contains("20"));
+}
+
+#[test]
+fn eq() {
+    let a = Force::from_newtons(10.0) * Length::from_metres(2.0);
+    let b = Force::from_newtons(20.0) * Length::from_metres(1.0);
+    let c = Force::from_newtons(10.0) * Length::from_metres(3.0);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+}
+
+#[test]
+fn cmp() {
+    let a = Force::from_newtons(10.0) * Length::from_metres(2.0);
+    let b = Force::from_newtons(10.0) * Length::from_metres(3.0);
+    assert!(a < b);
+    assert!(a <= b);
+    assert!(b > a);
+    assert!(b >= a);
+}
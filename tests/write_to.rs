@@ -0,0 +1,35 @@
+extern crate heapless;
+extern crate measurements;
+
+use measurements::{Length, Measurement, Temperature};
+
+#[test]
+fn length_into_heapless_string() {
+    let mut buf: heapless::String<32> = heapless::String::new();
+    Length::from_kilometers(1.5).write_to(&mut buf).unwrap();
+    assert_eq!(buf.as_str(), "1.5\u{00A0}km");
+}
+
+#[test]
+fn matches_display() {
+    let mut buf: heapless::String<32> = heapless::String::new();
+    let t = Temperature::from_celsius(21.5);
+    t.write_to(&mut buf).unwrap();
+    assert_eq!(buf.as_str(), format!("{}", t));
+}
+
+#[test]
+#[cfg(feature = "sig_fig_display")]
+fn matches_rounded_display() {
+    let mut buf: heapless::String<32> = heapless::String::new();
+    let l = Length::from_meters(0.737_563_265_225_88);
+    l.write_to(&mut buf).unwrap();
+    assert_eq!(buf.as_str(), "73.7563\u{00A0}cm");
+    assert_eq!(buf.as_str(), format!("{}", l));
+}
+
+#[test]
+fn buffer_too_small() {
+    let mut buf: heapless::String<4> = heapless::String::new();
+    assert!(Length::from_kilometers(1.5).write_to(&mut buf).is_err());
+}